@@ -1,9 +1,17 @@
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Field, Fields, Index, LitInt, LitStr,
+    Member, Type,
+};
 
 /// Use this to derive the trait `FetchRow` for structs defined in the application logic.
 ///
+/// Both structs with named fields and tuple structs are supported. By default the fields are bound
+/// to the columns of the result set in order. Use `#[odbc(column = N)]` to bind a field to the
+/// column with the one based index `N`. Fields following it are bound to the consecutive columns.
+/// Use `#[odbc(skip)]` for fields which should not be bound to any column.
+///
 /// # Example
 ///
 /// ```
@@ -37,67 +45,431 @@ use syn::{parse_macro_input, DeriveInput};
 ///     Ok(())
 /// }
 /// ```
-#[proc_macro_derive(Fetch)]
+///
+/// Binding fields out of order:
+///
+/// ```
+/// use odbc_api_derive::Fetch;
+/// use odbc_api::parameter::VarCharArray;
+///
+/// // Fetches rows of `SELECT id, name, comment FROM Persons`.
+/// #[derive(Default, Clone, Copy, Fetch)]
+/// struct Person {
+///     #[odbc(column = 2)]
+///     name: VarCharArray<255>,
+///     #[odbc(column = 1)]
+///     id: i64,
+///     // Not fetched from the database
+///     #[odbc(skip)]
+///     visited: bool,
+/// }
+/// ```
+#[proc_macro_derive(Fetch, attributes(odbc))]
 pub fn derive_fetch_row(item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as DeriveInput);
+    expand_fetch_row(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-    let struct_name = input.ident;
-
-    let struct_data = match input.data {
-        syn::Data::Struct(struct_data) => struct_data,
-        _ => panic!("Fetch can only be derived for structs"),
+fn expand_fetch_row(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match input.data {
+        Data::Struct(struct_data) => struct_data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "Fetch can only be derived for structs",
+            ))
+        }
     };
 
-    let fields = struct_data.fields;
-
-    let bindings = fields.iter().enumerate().map(|(index, field)| {
-        let field_name = field
-            .ident
-            .as_ref()
-            .expect("All struct members must be named");
-        let col_index = (index + 1) as u16;
-        quote! {
-            odbc_api::buffers::FetchRowMember::bind_to_col(
-                &mut self.#field_name,
-                #col_index,
-                &mut cursor
-            )?;
-        }
-    });
-
-    let find_truncation = fields.iter().enumerate().map(|(index, field)| {
-        let field_name = field
-            .ident
-            .as_ref()
-            .expect("All struct members must be named");
-        quote! {
-            let maybe_truncation = odbc_api::buffers::FetchRowMember::find_truncation(
-                &self.#field_name,
-                #index,
-            );
-            if let Some(truncation_info) = maybe_truncation {
-                return Some(truncation_info);
-            }
-        }
-    });
+    let bound = bound_fields(&fields, Derive::Fetch)?;
 
-    let expanded = quote! {
-        unsafe impl odbc_api::buffers::FetchRow for #struct_name {
+    let members: Vec<_> = bound.iter().map(|(member, _, _)| member).collect();
+    let columns: Vec<_> = bound.iter().map(|&(_, _, column)| column).collect();
+    // The buffer index reported for truncations is the zero based column index.
+    let buffer_indices = columns.iter().map(|&column| usize::from(column - 1));
+    let column_count = columns.iter().copied().max().unwrap_or(0);
 
+    // Each bound field type must be bindable, this is also true for generic fields.
+    let mut generics = input.generics;
+    let where_clause = generics.make_where_clause();
+    for (_, ty, _) in &bound {
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: odbc_api::buffers::FetchRowMember));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let struct_name = input.ident;
+
+    Ok(quote! {
+        unsafe impl #impl_generics odbc_api::buffers::FetchRow for #struct_name #ty_generics
+            #where_clause
+        {
             unsafe fn bind_columns_to_cursor(
                 &mut self,
                 mut cursor: odbc_api::handles::StatementRef<'_>
             ) -> std::result::Result<(), odbc_api::Error> {
-                #(#bindings)*
+                #(
+                    odbc_api::buffers::FetchRowMember::bind_to_col(
+                        &mut self.#members,
+                        #columns,
+                        &mut cursor
+                    )?;
+                )*
                 Ok(())
             }
 
             fn find_truncation(&self) -> std::option::Option<odbc_api::TruncationInfo> {
-                #(#find_truncation)*
+                #(
+                    let maybe_truncation = odbc_api::buffers::FetchRowMember::find_truncation(
+                        &self.#members,
+                        #buffer_indices,
+                    );
+                    if let Some(truncation_info) = maybe_truncation {
+                        return Some(truncation_info);
+                    }
+                )*
                 None
             }
+
+            fn column_count() -> u16 {
+                #column_count
+            }
+        }
+    })
+}
+
+/// Fields bound to a column (or parameter) by `Fetch` (or `Insert`), together with the one based
+/// index of that column. Fields without an explicit `#[odbc(column = N)]` are bound to the column
+/// following the one of the previous field. Fields marked with `#[odbc(skip)]` are not bound.
+fn bound_fields(fields: &Fields, derive: Derive) -> syn::Result<Vec<(Member, &Type, u16)>> {
+    let mut bound = Vec::with_capacity(fields.len());
+    // Fields without an explicit column bind to the column following the previous one.
+    let mut next_column: u32 = 1;
+    for (index, field) in fields.iter().enumerate() {
+        let attrs = FieldAttrs::parse(field, derive)?;
+        if attrs.skip {
+            if let Some(column) = attrs.column {
+                return Err(syn::Error::new_spanned(
+                    column,
+                    "a skipped field can not be bound to a column",
+                ));
+            }
+            continue;
+        }
+        let column = match attrs.column {
+            Some(column) => {
+                let index: u16 = column.base10_parse()?;
+                if index == 0 {
+                    return Err(syn::Error::new_spanned(column, "column indices start at 1"));
+                }
+                index
+            }
+            None => u16::try_from(next_column).map_err(|_| {
+                syn::Error::new_spanned(field, "column index exceeds the maximum of 65535")
+            })?,
+        };
+        if bound.iter().any(|&(_, _, other)| other == column) {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!("column {column} is already bound to another field"),
+            ));
+        }
+        next_column = u32::from(column) + 1;
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
+        bound.push((member, &field.ty, column));
+    }
+    Ok(bound)
+}
+
+/// Use this to derive the trait `InsertRow` for structs defined in the application logic. This
+/// allows for inserting a `RowVec` of these structs in bulk, using row wise parameter arrays.
+///
+/// Fields are bound to the parameter placeholders of the statement in order. The same attributes
+/// as for `Fetch` are supported: `#[odbc(column = N)]` binds a field to the parameter with the one
+/// based index `N` and `#[odbc(skip)]` does not bind the field at all. This way a struct may derive
+/// both `Fetch` and `Insert`.
+///
+/// The types of bound fields must implement both `FetchRowMember` and `InputParameter`, so their
+/// values are stored within the struct. Types referencing memory outside of the row, like
+/// `VarCharSlice`, are rejected.
+///
+/// # Example
+///
+/// ```
+/// use odbc_api_derive::{Fetch, Insert};
+/// use odbc_api::{Connection, Error, parameter::VarCharArray, buffers::RowVec};
+///
+/// #[derive(Default, Clone, Copy, Fetch, Insert)]
+/// struct Person {
+///     first_name: VarCharArray<255>,
+///     last_name: VarCharArray<255>,
+/// }
+///
+/// fn insert_persons(conn: &Connection, persons: &[(&str, &str)]) -> Result<(), Error> {
+///     let mut buffer = RowVec::<Person>::new(persons.len());
+///     buffer.set_num_rows(persons.len());
+///     for (row, (first, last)) in buffer.iter_mut().zip(persons) {
+///         row.first_name = VarCharArray::new(first.as_bytes());
+///         row.last_name = VarCharArray::new(last.as_bytes());
+///     }
+///     conn.execute("INSERT INTO Persons (first_name, last_name) VALUES (?, ?)", &buffer)?;
+///     Ok(())
+/// }
+/// ```
+#[proc_macro_derive(Insert, attributes(odbc))]
+pub fn derive_insert_row(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    expand_insert_row(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_insert_row(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match input.data {
+        Data::Struct(struct_data) => struct_data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "Insert can only be derived for structs",
+            ))
+        }
+    };
+
+    let bound = bound_fields(&fields, Derive::Insert)?;
+    let members: Vec<_> = bound.iter().map(|(member, _, _)| member).collect();
+    let parameters = bound.iter().map(|&(_, _, parameter)| parameter);
+
+    // Each bound field type must be an input parameter. It must also be a `FetchRowMember`, which
+    // guarantees its value and indicator are stored inline, as the driver locates the parameters of
+    // each row by their offset from the first row. This is also true for generic fields.
+    let mut generics = input.generics;
+    let where_clause = generics.make_where_clause();
+    for (_, ty, _) in &bound {
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: odbc_api::buffers::FetchRowMember
+                + odbc_api::parameter::InputParameter));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let struct_name = input.ident;
+
+    Ok(quote! {
+        unsafe impl #impl_generics odbc_api::buffers::InsertRow for #struct_name #ty_generics
+            #where_clause
+        {
+            unsafe fn bind_parameters_to_statement(
+                &self,
+                stmt: &mut impl odbc_api::handles::Statement,
+            ) -> std::result::Result<(), odbc_api::Error> {
+                #(
+                    stmt.bind_input_parameter(#parameters, &self.#members).into_result(stmt)?;
+                )*
+                Ok(())
+            }
+
+            fn assert_completness(&self) {
+                #(
+                    odbc_api::parameter::CElement::assert_completness(&self.#members);
+                )*
+            }
+        }
+    })
+}
+
+/// Use this to derive the trait `FromRow` for structs defined in the application logic. Each field
+/// is read from the result set column with the same name as the field. Use
+/// `#[odbc(rename = "...")]` to read a field from a column with a different name. Field types must
+/// implement `FromSql`, e.g. integers, `String`, `Vec<u8>` or `Option` of these. Since columns are
+/// mapped by name, the `column` and `skip` attributes of `Fetch` are not supported.
+///
+/// # Example
+///
+/// ```
+/// use odbc_api_derive::FromRow;
+/// use odbc_api::{Connection, Cursor, Error};
+///
+/// #[derive(FromRow)]
+/// struct Person {
+///     #[odbc(rename = "first_name")]
+///     first: String,
+///     last_name: Option<String>,
+///     age: Option<i16>,
+/// }
+///
+/// fn send_greetings(conn: &mut Connection) -> Result<(), Error> {
+///     let cursor = conn.execute("SELECT age, first_name, last_name FROM Persons", ())?
+///         .expect("SELECT must yield a result set");
+///     for person in cursor.rows_as::<Person>() {
+///         let person = person?;
+///         let last = person.last_name.as_deref().unwrap_or_default();
+///         println!("Hello {} {last}!", person.first)
+///     }
+///     Ok(())
+/// }
+/// ```
+#[proc_macro_derive(FromRow, attributes(odbc))]
+pub fn derive_from_row(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    expand_from_row(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_from_row(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match input.data {
+        Data::Struct(struct_data) => match struct_data.fields {
+            Fields::Named(fields) => fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "FromRow can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "FromRow can only be derived for structs",
+            ))
         }
     };
 
-    expanded.into()
+    let mut column_names = Vec::with_capacity(fields.len());
+    for field in &fields {
+        let column_name = match FieldAttrs::parse(field, Derive::FromRow)?.rename {
+            Some(rename) => rename.value(),
+            None => field.ident.as_ref().unwrap().to_string(),
+        };
+        column_names.push(column_name);
+    }
+
+    let field_names: Vec<_> = fields.iter().map(|field| &field.ident).collect();
+    // Prefixed, so the locals can not collide with the other identifiers in the generated code.
+    let values: Vec<_> = (0..fields.len())
+        .map(|index| format_ident!("__value_{}", index))
+        .collect();
+    let field_indices: Vec<_> = (0..fields.len()).collect();
+    let num_fields = fields.len();
+
+    // Each field type must be fetchable, this is also true for generic fields.
+    let mut generics = input.generics;
+    let where_clause = generics.make_where_clause();
+    for field in &fields {
+        let ty = &field.ty;
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: odbc_api::FromSql));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let struct_name = input.ident;
+
+    Ok(quote! {
+        impl #impl_generics odbc_api::FromRow for #struct_name #ty_generics #where_clause {
+            fn column_names() -> &'static [&'static str] {
+                &[#(#column_names),*]
+            }
+
+            fn from_row(
+                row: &mut odbc_api::CursorRow<'_>,
+                columns: &[u16],
+            ) -> std::result::Result<Self, odbc_api::Error> {
+                #(let mut #values = std::option::Option::None;)*
+                // Many drivers only support reading the fields of a row in ascending column order.
+                let mut order: [usize; #num_fields] = [#(#field_indices),*];
+                order.sort_unstable_by_key(|&field| columns[field]);
+                for field in order {
+                    match field {
+                        #(#field_indices => #values = std::option::Option::Some(
+                            row.get(columns[#field_indices])?
+                        ),)*
+                        _ => unreachable!(),
+                    }
+                }
+                Ok(Self {
+                    #(#field_names: #values.unwrap(),)*
+                })
+            }
+        }
+    })
+}
+
+/// Arguments of the `#[odbc(...)]` attributes of a field. Each derive only accepts the arguments
+/// listed by [`Derive::attributes`].
+#[derive(Default)]
+struct FieldAttrs {
+    /// `#[odbc(rename = "...")]`: Name of the column, if it differs from the field name. Used by
+    /// `FromRow`.
+    rename: Option<LitStr>,
+    /// `#[odbc(column = N)]`: One based index of the column the field is bound to. Used by `Fetch`
+    /// and `Insert`.
+    column: Option<LitInt>,
+    /// `#[odbc(skip)]`: The field is not bound to any column. Used by `Fetch` and `Insert`.
+    skip: bool,
+}
+
+impl FieldAttrs {
+    /// Parses the `odbc` attributes of `field`. Attributes which do not apply to `derive` are
+    /// rejected, rather than silently ignored.
+    fn parse(field: &Field, derive: Derive) -> syn::Result<Self> {
+        let mut attrs = FieldAttrs::default();
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("odbc"))
+        {
+            attr.parse_nested_meta(|meta| {
+                let supported = derive.attributes();
+                if !supported.iter().any(|name| meta.path.is_ident(name)) {
+                    let expected: Vec<_> =
+                        supported.iter().map(|name| format!("`{name}`")).collect();
+                    return Err(meta.error(format!(
+                        "unsupported odbc attribute for `#[derive({})]`, expected {}",
+                        derive.name(),
+                        expected.join(" or ")
+                    )));
+                }
+                if meta.path.is_ident("rename") {
+                    attrs.rename = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("column") {
+                    attrs.column = Some(meta.value()?.parse()?);
+                } else {
+                    // Only `skip` is left, any other argument has been rejected above.
+                    attrs.skip = true;
+                }
+                Ok(())
+            })?;
+        }
+        Ok(attrs)
+    }
+}
+
+/// Derive macro the `odbc` field attributes are parsed for.
+#[derive(Clone, Copy)]
+enum Derive {
+    Fetch,
+    Insert,
+    FromRow,
+}
+
+impl Derive {
+    /// Names of the `odbc` field attributes which apply to this derive.
+    fn attributes(self) -> &'static [&'static str] {
+        match self {
+            Derive::Fetch | Derive::Insert => &["column", "skip"],
+            Derive::FromRow => &["rename"],
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Derive::Fetch => "Fetch",
+            Derive::Insert => "Insert",
+            Derive::FromRow => "FromRow",
+        }
+    }
 }
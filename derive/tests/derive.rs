@@ -1,5 +1,5 @@
-use odbc_api::parameter::VarCharArray;
-use odbc_api_derive::Fetch;
+use odbc_api::{buffers::FetchRow, parameter::VarCharArray};
+use odbc_api_derive::{Fetch, FromRow, Insert};
 
 // A check, wether the derive syntax produces something that compiles. For a test actually fetching
 // date from a database using this generated code, run the integration tests of `odbc-api` with the
@@ -11,3 +11,40 @@ struct MyRow {
     a: i64,
     b: VarCharArray<50>,
 }
+
+#[allow(dead_code)]
+#[derive(Fetch, Insert, Clone, Copy)]
+struct MyTupleRow(i32, VarCharArray<50>);
+
+#[allow(dead_code)]
+#[derive(Fetch, Insert, Clone, Copy)]
+struct MyGenericFetchRow<T: Copy> {
+    a: T,
+    #[odbc(column = 3)]
+    c: i64,
+    #[odbc(skip)]
+    not_bound: bool,
+}
+
+#[test]
+fn column_count_is_highest_bound_column() {
+    assert_eq!(2, MyRow::column_count());
+    assert_eq!(2, MyTupleRow::column_count());
+    assert_eq!(3, MyGenericFetchRow::<i16>::column_count());
+}
+
+#[allow(dead_code)]
+#[derive(FromRow)]
+struct MyNamedRow {
+    #[odbc(rename = "a")]
+    id: i64,
+    b: String,
+    c: Option<Vec<u8>>,
+}
+
+// Generic fields must implement `FromSql`. The derive adds the bound.
+#[allow(dead_code)]
+#[derive(FromRow)]
+struct MyGenericRow<T> {
+    value: Option<T>,
+}
@@ -0,0 +1,240 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use futures_core::Stream;
+
+use crate::{
+    buffers::{BufferDesc, ColumnarAnyBuffer},
+    handles::AsStatementRef,
+    BlockCursorPolling, CursorPolling, Error, RowSetBuffer, Sleep,
+};
+
+/// Adapts a [`BlockCursorPolling`] into a [`futures_core::Stream`] of owned batches. Created using
+/// [`BlockCursorPolling::into_stream`]. Requires the `futures` feature.
+///
+/// Like [`crate::ConcurrentBlockCursor`] this uses a double buffer strategy. Once a batch has been
+/// fetched, its buffer is unbound and handed to the application, while another buffer is bound to
+/// the cursor and the fetch of the next batch is started right away. If the driver supports
+/// asynchronous execution, the next batch is fetched while the application processes the current
+/// one. Otherwise the next batch is fetched before the current one is yielded.
+///
+/// The buffer bound for the next batch is either a buffer the application handed back using
+/// [`Self::fill`], or a newly allocated one with the same layout. [`Self::fill`] requires access to
+/// the stream itself. Once the stream has been moved into a combinator (like `try_fold` in the
+/// example below) buffers can no longer be handed back and a new buffer is allocated for each
+/// batch. To recycle buffers, drive the stream using e.g. `try_next` in a loop instead.
+///
+/// Each batch is fetched with a truncation check, i.e. truncated values are reported as
+/// [`Error::TooLargeValueForBuffer`].
+///
+/// Dropping the stream while a fetch is still executing cancels the fetch. Dropping blocks the
+/// current thread until the driver confirms the cancellation.
+///
+/// # Example
+///
+/// ```no_run
+/// use odbc_api::{
+///     buffers::{BufferDesc, ColumnarAnyBuffer}, Connection, Error,
+/// };
+/// use futures_util::TryStreamExt;
+/// use std::time::Duration;
+///
+/// async fn sum_ints(conn: &Connection<'_>) -> Result<i64, Error> {
+///     // Poll every 50 ms.
+///     let sleep = || tokio::time::sleep(Duration::from_millis(50));
+///     let cursor = conn.execute_polling("SELECT a FROM Numbers", (), sleep).await?.unwrap();
+///     let buffer = ColumnarAnyBuffer::from_descs(1000, [BufferDesc::I64 { nullable: false }]);
+///     let batches = cursor.bind_buffer(buffer)?.into_stream(sleep);
+///     batches
+///         .try_fold(0, |sum, batch| async move {
+///             let values = batch.column(0).as_slice::<i64>().unwrap();
+///             Ok(sum + values.iter().sum::<i64>())
+///         })
+///         .await
+/// }
+///
+/// async fn sum_ints_recycling_buffers(conn: &Connection<'_>) -> Result<i64, Error> {
+///     let sleep = || tokio::time::sleep(Duration::from_millis(50));
+///     let cursor = conn.execute_polling("SELECT a FROM Numbers", (), sleep).await?.unwrap();
+///     let buffer = ColumnarAnyBuffer::from_descs(1000, [BufferDesc::I64 { nullable: false }]);
+///     let mut batches = cursor.bind_buffer(buffer)?.into_stream(sleep);
+///     let mut sum = 0;
+///     while let Some(batch) = batches.try_next().await? {
+///         sum += batch.column(0).as_slice::<i64>().unwrap().iter().sum::<i64>();
+///         // Fetch the next batch into the same buffer, rather than allocating a new one.
+///         batches.fill(batch)?;
+///     }
+///     Ok(sum)
+/// }
+/// ```
+pub struct BlockCursorStream<S, Z>
+where
+    S: AsStatementRef,
+    Z: Sleep,
+{
+    /// Cursor with a buffer bound to it, which is fetching (or about to fetch) the next batch.
+    /// `None` once the result set has been consumed completely, or an error occurred.
+    block_cursor: Option<BlockCursorPolling<CursorPolling<S>, ColumnarAnyBuffer>>,
+    /// Outcome of a fetch which completed, but has not been reported by [`Stream::poll_next`] yet.
+    /// Also holds errors binding the next buffer, which occurred after yielding a batch.
+    fetched: Option<Result<bool, Error>>,
+    /// `true` while the driver is still executing a fetch.
+    executing: bool,
+    /// Capacity of the buffers of this stream.
+    row_array_size: usize,
+    /// Column indices and buffer descriptions of the buffers of this stream.
+    descs: Vec<(u16, BufferDesc)>,
+    /// Buffer handed back by the application via [`Self::fill`]. It is bound to the cursor in order
+    /// to fetch the batch after the one currently being fetched.
+    spare: Option<ColumnarAnyBuffer>,
+    sleep: Z,
+    /// `Some` while waiting to poll the driver again. Awaited in between polling the driver.
+    pending_poll: Option<Pin<Box<Z::Poll>>>,
+}
+
+impl<S, Z> BlockCursorStream<S, Z>
+where
+    S: AsStatementRef,
+    Z: Sleep,
+{
+    fn new(
+        block_cursor: BlockCursorPolling<CursorPolling<S>, ColumnarAnyBuffer>,
+        sleep: Z,
+    ) -> Self {
+        let buffer = block_cursor.row_array();
+        let row_array_size = buffer.row_array_size();
+        let descs = layout(buffer);
+        Self {
+            block_cursor: Some(block_cursor),
+            fetched: None,
+            executing: false,
+            row_array_size,
+            descs,
+            spare: None,
+            sleep,
+            pending_poll: None,
+        }
+    }
+
+    /// Hand a buffer back to the stream, so it can be used to fetch a future batch, rather than
+    /// allocating a new one. The buffer should have been yielded by this stream before.
+    ///
+    /// # Return
+    ///
+    /// [`Error::BufferLayoutMismatch`] if the buffer does not have the same capacity, column
+    /// indices and column types as the buffers of this stream. The buffer is dropped in that case.
+    pub fn fill(&mut self, buffer: ColumnarAnyBuffer) -> Result<(), Error> {
+        if buffer.row_array_size() != self.row_array_size || layout(&buffer) != self.descs {
+            return Err(Error::BufferLayoutMismatch);
+        }
+        self.spare = Some(buffer);
+        Ok(())
+    }
+
+    /// Calls `SQLFetch` once. Stores the outcome, if the fetch completed.
+    fn poll_driver(&mut self) {
+        let block_cursor = self.block_cursor.as_mut().unwrap();
+        self.fetched = block_cursor.poll_fetch(true);
+        self.executing = self.fetched.is_none();
+    }
+
+    /// Unbinds the buffer holding the last batch fetched, so it can be yielded to the application.
+    /// Binds another buffer in its place and starts fetching the next batch. Should binding fail,
+    /// the error is reported after the batch.
+    fn take_batch(&mut self) -> Result<ColumnarAnyBuffer, Error> {
+        let block_cursor = self.block_cursor.take().unwrap();
+        let (cursor, batch) = block_cursor.unbind()?;
+        let buffer = self.spare.take().unwrap_or_else(|| {
+            ColumnarAnyBuffer::from_descs_and_indices(
+                self.row_array_size,
+                self.descs.iter().copied(),
+            )
+        });
+        match cursor.bind_buffer(buffer) {
+            Ok(block_cursor) => {
+                self.block_cursor = Some(block_cursor);
+                self.poll_driver();
+            }
+            Err(error) => self.fetched = Some(Err(error)),
+        }
+        Ok(batch)
+    }
+}
+
+/// Column indices and buffer descriptions of a buffer. Two buffers with the same layout and
+/// capacity can be used interchangeably.
+fn layout(buffer: &ColumnarAnyBuffer) -> Vec<(u16, BufferDesc)> {
+    buffer
+        .columns()
+        .iter()
+        .map(|(col_index, column)| (*col_index, column.desc()))
+        .collect()
+}
+
+impl<S> BlockCursorPolling<CursorPolling<S>, ColumnarAnyBuffer>
+where
+    S: AsStatementRef,
+{
+    /// Consumes the block cursor and yields its batches as a [`futures_core::Stream`]. See
+    /// [`BlockCursorStream`]. Requires the `futures` feature.
+    pub fn into_stream<Z: Sleep>(self, sleep: Z) -> BlockCursorStream<S, Z> {
+        BlockCursorStream::new(self, sleep)
+    }
+}
+
+impl<S, Z> Stream for BlockCursorStream<S, Z>
+where
+    S: AsStatementRef + Unpin,
+    Z: Sleep + Unpin,
+{
+    type Item = Result<ColumnarAnyBuffer, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(pending_poll) = this.pending_poll.as_mut() {
+                ready!(pending_poll.as_mut().poll(cx));
+                this.pending_poll = None;
+            }
+            if this.fetched.is_none() {
+                if this.block_cursor.is_none() {
+                    return Poll::Ready(None);
+                }
+                this.poll_driver();
+            }
+            match this.fetched.take() {
+                // Still executing. Wait before we poll the driver again.
+                None => this.pending_poll = Some(Box::pin(this.sleep.next_poll())),
+                // Should unbinding fail, the cursor is gone and the stream ends after reporting
+                // the error.
+                Some(Ok(true)) => return Poll::Ready(Some(this.take_batch())),
+                Some(Ok(false)) => {
+                    this.block_cursor = None;
+                    return Poll::Ready(None);
+                }
+                Some(Err(error)) => {
+                    this.block_cursor = None;
+                    return Poll::Ready(Some(Err(error)));
+                }
+            }
+        }
+    }
+}
+
+impl<S, Z> Drop for BlockCursorStream<S, Z>
+where
+    S: AsStatementRef,
+    Z: Sleep,
+{
+    fn drop(&mut self) {
+        if self.executing {
+            if let Some(block_cursor) = self.block_cursor.as_mut() {
+                // The statement must not be closed while the fetch is still executing.
+                block_cursor.cancel_fetch();
+            }
+        }
+    }
+}
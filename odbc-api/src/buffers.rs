@@ -42,7 +42,7 @@ pub use self::{
     description::BufferDesc,
     indicator::Indicator,
     item::Item,
-    row_vec::{FetchRow, FetchRowMember, RowVec},
+    row_vec::{FetchRow, FetchRowMember, InsertRow, RowVec},
     text_column::{
         CharColumn, TextColumn, TextColumnIt, TextColumnSliceMut, TextColumnView, WCharColumn,
     },
@@ -3,7 +3,7 @@ use std::{collections::HashSet, ffi::c_void};
 use odbc_sys::{CDataType, Date, Time, Timestamp};
 
 use crate::{
-    columnar_bulk_inserter::BoundInputSlice,
+    columnar_bulk_inserter::{BoundAt, BoundInputSlice},
     error::TooLargeBufferSize,
     handles::{CData, CDataMut, HasDataType, StatementRef},
     Bit, DataType, Error,
@@ -152,6 +152,44 @@ impl AnyBuffer {
         Ok(buffer)
     }
 
+    /// Description of the buffer. Can be used to allocate another buffer of the same kind.
+    #[cfg(feature = "futures")]
+    pub(crate) fn desc(&self) -> BufferDesc {
+        match self {
+            AnyBuffer::Binary(col) => BufferDesc::Binary {
+                length: col.max_len(),
+            },
+            AnyBuffer::Text(col) => BufferDesc::Text {
+                max_str_len: col.max_len(),
+            },
+            AnyBuffer::WText(col) => BufferDesc::WText {
+                max_str_len: col.max_len(),
+            },
+            AnyBuffer::Date(_) => BufferDesc::Date { nullable: false },
+            AnyBuffer::Time(_) => BufferDesc::Time { nullable: false },
+            AnyBuffer::Timestamp(_) => BufferDesc::Timestamp { nullable: false },
+            AnyBuffer::F64(_) => BufferDesc::F64 { nullable: false },
+            AnyBuffer::F32(_) => BufferDesc::F32 { nullable: false },
+            AnyBuffer::I8(_) => BufferDesc::I8 { nullable: false },
+            AnyBuffer::I16(_) => BufferDesc::I16 { nullable: false },
+            AnyBuffer::I32(_) => BufferDesc::I32 { nullable: false },
+            AnyBuffer::I64(_) => BufferDesc::I64 { nullable: false },
+            AnyBuffer::U8(_) => BufferDesc::U8 { nullable: false },
+            AnyBuffer::Bit(_) => BufferDesc::Bit { nullable: false },
+            AnyBuffer::NullableDate(_) => BufferDesc::Date { nullable: true },
+            AnyBuffer::NullableTime(_) => BufferDesc::Time { nullable: true },
+            AnyBuffer::NullableTimestamp(_) => BufferDesc::Timestamp { nullable: true },
+            AnyBuffer::NullableF64(_) => BufferDesc::F64 { nullable: true },
+            AnyBuffer::NullableF32(_) => BufferDesc::F32 { nullable: true },
+            AnyBuffer::NullableI8(_) => BufferDesc::I8 { nullable: true },
+            AnyBuffer::NullableI16(_) => BufferDesc::I16 { nullable: true },
+            AnyBuffer::NullableI32(_) => BufferDesc::I32 { nullable: true },
+            AnyBuffer::NullableI64(_) => BufferDesc::I64 { nullable: true },
+            AnyBuffer::NullableU8(_) => BufferDesc::U8 { nullable: true },
+            AnyBuffer::NullableBit(_) => BufferDesc::Bit { nullable: true },
+        }
+    }
+
     fn fill_default_slice<T: Default + Copy>(col: &mut [T]) {
         let element = T::default();
         for item in col {
@@ -429,15 +467,26 @@ unsafe impl<'a> BoundInputSlice<'a> for AnyBuffer {
         parameter_index: u16,
         stmt: StatementRef<'a>,
     ) -> Self::SliceMut {
+        self.slice_mut(BoundAt::Parameter(parameter_index), stmt)
+    }
+}
+
+impl AnyBuffer {
+    /// Mutable view on a buffer bound to `stmt` at `bound_at`.
+    ///
+    /// # Safety
+    ///
+    /// `bound_at` must be the position the buffer is bound to `stmt` at.
+    pub(crate) unsafe fn slice_mut<'a>(
+        &'a mut self,
+        bound_at: BoundAt,
+        stmt: StatementRef<'a>,
+    ) -> AnySliceMut<'a> {
         let num_rows = self.capacity();
         match self {
-            AnyBuffer::Binary(column) => {
-                AnySliceMut::Binary(column.as_view_mut(parameter_index, stmt))
-            }
-            AnyBuffer::Text(column) => AnySliceMut::Text(column.as_view_mut(parameter_index, stmt)),
-            AnyBuffer::WText(column) => {
-                AnySliceMut::WText(column.as_view_mut(parameter_index, stmt))
-            }
+            AnyBuffer::Binary(column) => AnySliceMut::Binary(column.slice_mut(bound_at, stmt)),
+            AnyBuffer::Text(column) => AnySliceMut::Text(column.slice_mut(bound_at, stmt)),
+            AnyBuffer::WText(column) => AnySliceMut::WText(column.slice_mut(bound_at, stmt)),
             AnyBuffer::Date(column) => AnySliceMut::Date(column),
             AnyBuffer::Time(column) => AnySliceMut::Time(column),
             AnyBuffer::Timestamp(column) => AnySliceMut::Timestamp(column),
@@ -1,8 +1,8 @@
 use crate::{
     buffers::Indicator,
-    columnar_bulk_inserter::BoundInputSlice,
+    columnar_bulk_inserter::{BoundAt, BoundInputSlice},
     error::TooLargeBufferSize,
-    handles::{CData, CDataMut, HasDataType, Statement, StatementRef},
+    handles::{CData, CDataMut, HasDataType, StatementRef},
     DataType, Error,
 };
 
@@ -272,10 +272,25 @@ unsafe impl<'a> BoundInputSlice<'a> for BinColumn {
         parameter_index: u16,
         stmt: StatementRef<'a>,
     ) -> Self::SliceMut {
+        self.slice_mut(BoundAt::Parameter(parameter_index), stmt)
+    }
+}
+
+impl BinColumn {
+    /// Mutable view on a buffer bound to `stmt` at `bound_at`.
+    ///
+    /// # Safety
+    ///
+    /// `bound_at` must be the position the buffer is bound to `stmt` at.
+    pub(crate) unsafe fn slice_mut<'a>(
+        &'a mut self,
+        bound_at: BoundAt,
+        stmt: StatementRef<'a>,
+    ) -> BinColumnSliceMut<'a> {
         BinColumnSliceMut {
             column: self,
             stmt,
-            parameter_index,
+            bound_at,
         }
     }
 }
@@ -287,7 +302,7 @@ pub struct BinColumnSliceMut<'a> {
     // Needed to rebind the column in case of reallocation
     stmt: StatementRef<'a>,
     // Also needed to rebind the column in case of reallocation
-    parameter_index: u16,
+    bound_at: BoundAt,
 }
 
 impl<'a> BinColumnSliceMut<'a> {
@@ -313,11 +328,7 @@ impl<'a> BinColumnSliceMut<'a> {
         if element_length > self.column.max_len() {
             self.column
                 .resize_max_element_length(element_length, num_rows_to_copy);
-            unsafe {
-                self.stmt
-                    .bind_input_parameter(self.parameter_index, self.column)
-                    .into_result(&self.stmt)?
-            }
+            unsafe { self.bound_at.rebind(&mut self.stmt, self.column)? }
         }
         Ok(())
     }
@@ -6,7 +6,7 @@ use std::{
 
 use crate::{
     columnar_bulk_inserter::BoundInputSlice,
-    cursor::TruncationInfo,
+    cursor::{RowStatus, TruncationInfo},
     fixed_sized::Pod,
     handles::{CDataMut, Statement, StatementRef},
     parameter::WithDataType,
@@ -52,16 +52,31 @@ impl<C: ColumnBuffer> ColumnarBuffer<C> {
     pub unsafe fn new_unchecked(capacity: usize, columns: Vec<(u16, C)>) -> Self {
         ColumnarBuffer {
             num_rows: Box::new(0),
+            row_status: vec![0; capacity],
             row_capacity: capacity,
             columns,
         }
     }
 
+    /// Column indices and the buffers bound to them.
+    #[cfg(feature = "futures")]
+    pub(crate) fn columns(&self) -> &[(u16, C)] {
+        &self.columns
+    }
+
     /// Number of valid rows in the buffer.
     pub fn num_rows(&self) -> usize {
         *self.num_rows
     }
 
+    /// Status of the row at `row_index`, as reported by the driver during the last fetch. Rows
+    /// with [`RowStatus::Error`] do not hold valid values.
+    ///
+    /// Panics if `row_index` is not smaller than [`Self::num_rows`].
+    pub fn row_status(&self, row_index: usize) -> RowStatus {
+        RowStatus::from_raw(self.row_status[..*self.num_rows][row_index])
+    }
+
     /// Return the number of columns in the row set.
     pub fn num_cols(&self) -> usize {
         self.columns.len()
@@ -80,6 +95,27 @@ impl<C: ColumnBuffer> ColumnarBuffer<C> {
     pub fn column(&self, buffer_index: usize) -> C::View<'_> {
         self.columns[buffer_index].1.view(*self.num_rows)
     }
+
+    /// Column index and mutable access to the buffer bound to it.
+    pub(crate) fn column_buffer_mut(&mut self, buffer_index: usize) -> (u16, &mut C) {
+        let (col_index, column) = &mut self.columns[buffer_index];
+        (*col_index, column)
+    }
+
+    /// `Some` if any value in the first `num_rows` rows is truncated.
+    pub(crate) fn find_truncation_in(&self, num_rows: usize) -> Option<TruncationInfo> {
+        self.columns
+            .iter()
+            .enumerate()
+            .find_map(|(buffer_index, (_col_index, col_buffer))| {
+                col_buffer
+                    .has_truncated_values(num_rows)
+                    .map(|indicator| TruncationInfo {
+                        indicator: indicator.length(),
+                        buffer_index,
+                    })
+            })
+    }
 }
 
 unsafe impl<C> RowSetBuffer for ColumnarBuffer<C>
@@ -106,17 +142,11 @@ where
     }
 
     fn find_truncation(&self) -> Option<TruncationInfo> {
-        self.columns
-            .iter()
-            .enumerate()
-            .find_map(|(buffer_index, (_col_index, col_buffer))| {
-                col_buffer
-                    .has_truncated_values(*self.num_rows)
-                    .map(|indicator| TruncationInfo {
-                        indicator: indicator.length(),
-                        buffer_index,
-                    })
-            })
+        self.find_truncation_in(*self.num_rows)
+    }
+
+    fn mut_row_status(&mut self) -> Option<&mut [u16]> {
+        Some(&mut self.row_status)
     }
 }
 
@@ -223,6 +253,9 @@ pub struct ColumnarBuffer<C> {
     /// number of fetched rows. `num_rows` is heap allocated, so the pointer is not invalidated,
     /// even if the `ColumnarBuffer` instance is moved in memory.
     num_rows: Box<usize>,
+    /// Status of each row in the row set. Bound to the statement as well. Heap allocated for the
+    /// same reason as `num_rows`.
+    row_status: Vec<u16>,
     /// aka: batch size, row array size
     row_capacity: usize,
     /// Column index and bound buffer
@@ -437,6 +470,7 @@ impl TextRowSet {
         Ok(TextRowSet {
             row_capacity: batch_size,
             num_rows: Box::new(0),
+            row_status: vec![0; batch_size],
             columns: buffers,
         })
     }
@@ -461,6 +495,7 @@ impl TextRowSet {
         Ok(TextRowSet {
             row_capacity,
             num_rows: Box::new(0),
+            row_status: vec![0; row_capacity],
             columns: buffers,
         })
     }
@@ -1,9 +1,14 @@
-use std::{mem, ops::Deref};
+use std::{
+    mem,
+    ops::{Deref, DerefMut},
+};
 
 use crate::{
     buffers::Indicator,
     handles::{CDataMut, Statement, StatementRef},
-    Error, RowSetBuffer, TruncationInfo,
+    parameter::InputParameter,
+    parameter_collection::InputParameterCollection,
+    Error, RowSetBuffer, RowStatus, TruncationInfo,
 };
 
 /// [`FetchRow`]s can be bound to a [`crate::Cursor`] to enable row wise (bulk) fetching of data as
@@ -33,6 +38,52 @@ pub unsafe trait FetchRow: Copy {
 
     /// If it exists, this returns the "buffer index" of a member, which has been truncated.
     fn find_truncation(&self) -> Option<TruncationInfo>;
+
+    /// Highest (one based) index of the columns bound by [`Self::bind_columns_to_cursor`]. Binding
+    /// a [`RowVec`] fails with [`Error::ColumnCountMismatch`] if the result set has fewer columns.
+    ///
+    /// Defaults to `0`, which skips the check. Implemented by the `Fetch` derive and tuples.
+    fn column_count() -> u16 {
+        0
+    }
+}
+
+/// [`InsertRow`]s can be bound as parameters of a statement, so a [`RowVec`] can be inserted in
+/// bulk with a single roundtrip to the data source, using row wise parameter arrays. Pass a
+/// reference to the [`RowVec`] as parameters to e.g. [`crate::Prepared::execute`].
+///
+/// This trait is implemented by tuples of members implementing both [`FetchRowMember`] and
+/// [`InputParameter`]. In addition it can also be derived for structs there all members implement
+/// these traits using the `Insert` derive macro if the optional derive feature is activated.
+///
+/// Requiring [`FetchRowMember`] ensures value and indicator of each member are stored within the
+/// row itself. Types like [`crate::parameter::VarCharSlice`] point to memory outside of the row and
+/// can therefore not be part of an [`InsertRow`]:
+///
+/// ```compile_fail
+/// use odbc_api::{buffers::InsertRow, parameter::VarCharSlice};
+///
+/// fn assert_insert_row<R: InsertRow>() {}
+/// assert_insert_row::<(VarCharSlice<'static>,)>();
+/// ```
+///
+/// # Safety
+///
+/// * The offsets into the memory for the field representing a parameter, must be constant for all
+///   rows. Only the first row is bound explicitly, and the bindings for all consecutive rows are
+///   calculated by taking the size of the row in bytes multiplied by the row index.
+/// * Values and indicators of all bound parameters must be stored within the row.
+pub unsafe trait InsertRow: Copy {
+    /// Binds the members of the row as input parameters to the statement.
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure self is alive and not moved in memory for the duration of the binding.
+    unsafe fn bind_parameters_to_statement(&self, stmt: &mut impl Statement) -> Result<(), Error>;
+
+    /// Must panic if any member of the row is not complete. See
+    /// [`crate::parameter::CElement::assert_completness`].
+    fn assert_completness(&self);
 }
 
 /// A row wise buffer intended to be bound with [crate::Cursor::bind_buffer] in order to obtain
@@ -72,11 +123,17 @@ pub unsafe trait FetchRow: Copy {
 /// Currently supported are: `f64`, `f32`, [`odbc_sys::Date`], [`odbc_sys::Timestamp`],
 /// [`odbc_sys::Time`], `i16`, `u36`, `i32`, `u32`, `i8`, `u8`, `Bit`, `i64`, `u64` and
 /// [`crate::parameter::VarCharArray`]. Fixed sized types can be wrapped in [`crate::Nullable`].
+///
+/// If `R` implements [`InsertRow`], a reference to the buffer can also be passed as parameters to
+/// insert all its rows at once. Use [`Self::set_num_rows`] to specify the number of rows to insert.
 pub struct RowVec<R> {
     /// A mutable pointer to num_rows_fetched is passed to the C-API. It is used to write back the
     /// number of fetched rows. `num_rows` is heap allocated, so the pointer is not invalidated,
     /// even if the `ColumnarBuffer` instance is moved in memory.
     num_rows: Box<usize>,
+    /// Status of each row in the row set. Bound to the statement as well. Heap allocated for the
+    /// same reason as `num_rows`.
+    row_status: Vec<u16>,
     /// Here we actually store the rows. The length of `rows` is the capacity of the `RowWiseBuffer`
     /// instance. It must not be 0.
     rows: Vec<R>,
@@ -96,6 +153,7 @@ impl<R> RowVec<R> {
         }
         RowVec {
             num_rows: Box::new(0),
+            row_status: vec![0; capacity],
             rows: vec![R::default(); capacity],
         }
     }
@@ -104,6 +162,29 @@ impl<R> RowVec<R> {
     pub fn num_rows(&self) -> usize {
         *self.num_rows
     }
+
+    /// Sets the number of valid rows in the buffer. Use this to fill the buffer with rows to be
+    /// inserted. Rows which have not been valid before hold the values they had then the buffer
+    /// has been used the last time, or their default value.
+    ///
+    /// Panics if `num_rows` is larger than the capacity of the buffer.
+    pub fn set_num_rows(&mut self, num_rows: usize) {
+        if num_rows > self.rows.len() {
+            panic!(
+                "Number of rows {num_rows} exceeds the capacity {} of the RowVec.",
+                self.rows.len()
+            )
+        }
+        *self.num_rows = num_rows;
+    }
+
+    /// Status of the row at `row_index`, as reported by the driver during the last fetch. Rows
+    /// with [`RowStatus::Error`] do not hold valid values.
+    ///
+    /// Panics if `row_index` is not smaller than [`Self::num_rows`].
+    pub fn row_status(&self, row_index: usize) -> RowStatus {
+        RowStatus::from_raw(self.row_status[..*self.num_rows][row_index])
+    }
 }
 
 impl<R> Deref for RowVec<R> {
@@ -114,6 +195,12 @@ impl<R> Deref for RowVec<R> {
     }
 }
 
+impl<R> DerefMut for RowVec<R> {
+    fn deref_mut(&mut self) -> &mut [R] {
+        &mut self.rows[..*self.num_rows]
+    }
+}
+
 unsafe impl<R> RowSetBuffer for RowVec<R>
 where
     R: FetchRow,
@@ -131,6 +218,15 @@ where
     }
 
     unsafe fn bind_colmuns_to_cursor(&mut self, cursor: StatementRef<'_>) -> Result<(), Error> {
+        let expected = R::column_count();
+        let actual: u16 = cursor
+            .num_result_cols()
+            .into_result(&cursor)?
+            .try_into()
+            .unwrap();
+        if actual < expected {
+            return Err(Error::ColumnCountMismatch { expected, actual });
+        }
         let first = self
             .rows
             .first_mut()
@@ -144,6 +240,29 @@ where
             .take(*self.num_rows)
             .find_map(|row| row.find_truncation())
     }
+
+    fn mut_row_status(&mut self) -> Option<&mut [u16]> {
+        Some(&mut self.row_status)
+    }
+}
+
+unsafe impl<R> InputParameterCollection for RowVec<R>
+where
+    R: InsertRow,
+{
+    fn parameter_set_size(&self) -> usize {
+        *self.num_rows
+    }
+
+    unsafe fn bind_input_parameters_to(&self, stmt: &mut impl Statement) -> Result<(), Error> {
+        for row in self.iter() {
+            row.assert_completness();
+        }
+        stmt.set_param_bind_type(mem::size_of::<R>())
+            .into_result(stmt)?;
+        // Parameter set size is not zero, otherwise we would not be asked to bind.
+        self.rows[0].bind_parameters_to_statement(stmt)
+    }
 }
 
 /// Can be used as a member of a [`FetchRow`] and bound to a column during row wise fetching.
@@ -222,6 +341,38 @@ macro_rules! impl_fetch_row_for_tuple{
                 let ($(ref $t,)*) = self;
                 impl_find_truncation!(0, $($t,)*)
             }
+
+            fn column_count() -> u16 {
+                <[&str]>::len(&[$(stringify!($t),)*]) as u16
+            }
+        }
+    );
+}
+
+macro_rules! impl_insert_row_for_tuple{
+    ($($t:ident)*) => (
+        #[allow(unused_mut)]
+        #[allow(unused_variables)]
+        #[allow(non_snake_case)]
+        unsafe impl<$($t:FetchRowMember + InputParameter,)*> InsertRow for ($($t,)*)
+        {
+            unsafe fn bind_parameters_to_statement(
+                &self,
+                stmt: &mut impl Statement
+            ) -> Result<(), Error> {
+                let ($(ref $t,)*) = self;
+                let mut parameter_number = 0;
+                $(
+                    parameter_number += 1;
+                    stmt.bind_input_parameter(parameter_number, $t).into_result(stmt)?;
+                )*
+                Ok(())
+            }
+
+            fn assert_completness(&self) {
+                let ($(ref $t,)*) = self;
+                $($t.assert_completness();)*
+            }
         }
     );
 }
@@ -254,6 +405,34 @@ impl_fetch_row_for_tuple! { A B C D E F G H I J K L M N O P Q R S T U V W X }
 impl_fetch_row_for_tuple! { A B C D E F G H I J K L M N O P Q R S T U V W X Y }
 impl_fetch_row_for_tuple! { A B C D E F G H I J K L M N O P Q R S T U V W X Y Z}
 
+impl_insert_row_for_tuple! {}
+impl_insert_row_for_tuple! { A }
+impl_insert_row_for_tuple! { A B }
+impl_insert_row_for_tuple! { A B C }
+impl_insert_row_for_tuple! { A B C D }
+impl_insert_row_for_tuple! { A B C D E }
+impl_insert_row_for_tuple! { A B C D E F }
+impl_insert_row_for_tuple! { A B C D E F G }
+impl_insert_row_for_tuple! { A B C D E F G H }
+impl_insert_row_for_tuple! { A B C D E F G H I }
+impl_insert_row_for_tuple! { A B C D E F G H I J }
+impl_insert_row_for_tuple! { A B C D E F G H I J K }
+impl_insert_row_for_tuple! { A B C D E F G H I J K L }
+impl_insert_row_for_tuple! { A B C D E F G H I J K L M }
+impl_insert_row_for_tuple! { A B C D E F G H I J K L M N }
+impl_insert_row_for_tuple! { A B C D E F G H I J K L M N O }
+impl_insert_row_for_tuple! { A B C D E F G H I J K L M N O P }
+impl_insert_row_for_tuple! { A B C D E F G H I J K L M N O P Q }
+impl_insert_row_for_tuple! { A B C D E F G H I J K L M N O P Q R }
+impl_insert_row_for_tuple! { A B C D E F G H I J K L M N O P Q R S }
+impl_insert_row_for_tuple! { A B C D E F G H I J K L M N O P Q R S T }
+impl_insert_row_for_tuple! { A B C D E F G H I J K L M N O P Q R S T U }
+impl_insert_row_for_tuple! { A B C D E F G H I J K L M N O P Q R S T U V }
+impl_insert_row_for_tuple! { A B C D E F G H I J K L M N O P Q R S T U V W }
+impl_insert_row_for_tuple! { A B C D E F G H I J K L M N O P Q R S T U V W X }
+impl_insert_row_for_tuple! { A B C D E F G H I J K L M N O P Q R S T U V W X Y }
+impl_insert_row_for_tuple! { A B C D E F G H I J K L M N O P Q R S T U V W X Y Z }
+
 #[cfg(test)]
 mod tests {
 
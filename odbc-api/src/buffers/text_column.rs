@@ -1,7 +1,7 @@
 use crate::{
-    columnar_bulk_inserter::BoundInputSlice,
+    columnar_bulk_inserter::{BoundAt, BoundInputSlice},
     error::TooLargeBufferSize,
-    handles::{CData, CDataMut, HasDataType, Statement, StatementRef},
+    handles::{CData, CDataMut, HasDataType, StatementRef},
     DataType, Error,
 };
 
@@ -429,10 +429,25 @@ unsafe impl<'a, C: 'static> BoundInputSlice<'a> for TextColumn<C> {
         parameter_index: u16,
         stmt: StatementRef<'a>,
     ) -> Self::SliceMut {
+        self.slice_mut(BoundAt::Parameter(parameter_index), stmt)
+    }
+}
+
+impl<C> TextColumn<C> {
+    /// Mutable view on a buffer bound to `stmt` at `bound_at`.
+    ///
+    /// # Safety
+    ///
+    /// `bound_at` must be the position the buffer is bound to `stmt` at.
+    pub(crate) unsafe fn slice_mut<'a>(
+        &'a mut self,
+        bound_at: BoundAt,
+        stmt: StatementRef<'a>,
+    ) -> TextColumnSliceMut<'a, C> {
         TextColumnSliceMut {
             column: self,
             stmt,
-            parameter_index,
+            bound_at,
         }
     }
 }
@@ -444,7 +459,7 @@ pub struct TextColumnSliceMut<'a, C> {
     // Needed to rebind the column in case of resize
     stmt: StatementRef<'a>,
     // Also needed to rebind the column in case of resize
-    parameter_index: u16,
+    bound_at: BoundAt,
 }
 
 impl<'a, C> TextColumnSliceMut<'a, C>
@@ -468,7 +483,7 @@ where
         num_rows_to_copy: usize,
     ) -> Result<(), Error>
     where
-        TextColumn<C>: HasDataType + CData,
+        TextColumn<C>: HasDataType + CDataMut,
     {
         // Column buffer is not large enough to hold the element. We must allocate a larger buffer
         // in order to hold it. This invalidates the pointers previously bound to the statement. So
@@ -477,11 +492,7 @@ where
             let new_max_str_len = element_length;
             self.column
                 .resize_max_str(new_max_str_len, num_rows_to_copy);
-            unsafe {
-                self.stmt
-                    .bind_input_parameter(self.parameter_index, self.column)
-                    .into_result(&self.stmt)?
-            }
+            unsafe { self.bound_at.rebind(&mut self.stmt, self.column)? }
         }
         Ok(())
     }
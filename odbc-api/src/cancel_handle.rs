@@ -0,0 +1,74 @@
+use odbc_sys::HStmt;
+
+use crate::{
+    handles::{Statement, StatementRef},
+    Error,
+};
+
+/// Allows canceling the processing of a statement from a different thread. Obtained via e.g.
+/// [`crate::Preallocated::cancel_handle`], [`crate::Prepared::cancel_handle`],
+/// [`crate::CursorImpl::cancel_handle`] or [`crate::BlockCursor::cancel_handle`].
+///
+/// Calling [`CancelHandle::cancel`] causes a function currently blocking on the statement (e.g.
+/// executing a query or fetching a row set) to return [`Error::Cancelled`].
+///
+/// The cancel handle does not borrow the statement, since the statement must remain usable to
+/// execute or fetch while it may be canceled. Consequently it does not keep the statement alive
+/// either. This is why [`CancelHandle::cancel`] is `unsafe`: The application must ensure the
+/// statement the handle has been obtained from is still allocated then calling it.
+///
+/// # Example
+///
+/// ```no_run
+/// use odbc_api::{Connection, Error};
+/// use std::{thread, time::Duration};
+///
+/// fn execute_with_deadline(conn: &Connection<'_>, query: &str) -> Result<(), Error> {
+///     let mut statement = conn.preallocate()?;
+///     let cancel_handle = statement.cancel_handle();
+///     thread::scope(|s| {
+///         s.spawn(move || {
+///             thread::sleep(Duration::from_secs(10));
+///             // Has no effect if the statement is no longer executing.
+///             // Safety: `statement` outlives the scope and therefore this thread.
+///             unsafe { cancel_handle.cancel() }.unwrap();
+///         });
+///         match statement.execute(query, ()) {
+///             Err(Error::Cancelled { .. }) => {
+///                 eprintln!("Query took longer than ten seconds.");
+///                 Ok(())
+///             }
+///             other => other.map(|_| ()),
+///         }
+///     })
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CancelHandle {
+    statement: HStmt,
+}
+
+/// `SQLCancel` is explicitly intended to be called from another thread than the one processing
+/// the statement.
+unsafe impl Send for CancelHandle {}
+unsafe impl Sync for CancelHandle {}
+
+impl CancelHandle {
+    pub(crate) fn new(statement: HStmt) -> Self {
+        Self { statement }
+    }
+
+    /// Cancel the processing of the statement. Has no effect if no function is currently
+    /// processing the statement.
+    ///
+    /// # Safety
+    ///
+    /// The statement this handle has been obtained from must not have been freed yet, i.e. the
+    /// [`crate::Preallocated`], [`crate::Prepared`] or cursor owning it must still be alive.
+    /// Otherwise the handle passed to `SQLCancel` is dangling, or may even refer to a different
+    /// statement allocated in the meantime.
+    pub unsafe fn cancel(&self) -> Result<(), Error> {
+        let stmt = unsafe { StatementRef::new(self.statement) };
+        stmt.cancel().into_result(&stmt)
+    }
+}
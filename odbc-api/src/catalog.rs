@@ -0,0 +1,607 @@
+use std::marker::PhantomData;
+
+use odbc_sys::SqlDataType;
+
+use crate::{
+    buffers::{FetchRow, FetchRowMember},
+    handles::StatementRef,
+    parameter::VarCharArray,
+    Cursor, CursorRow, DataType, Error, Nullability, Nullable, TruncationInfo,
+};
+
+/// Size in bytes of the buffers holding identifiers (e.g. table or column names) in the typed rows
+/// of catalog functions. Longer names are truncated. 128 characters is the maximum identifier
+/// length of Microsoft SQL Server, which also covers e.g. PostgreSQL (63) or MariaDB (64). Each
+/// character may take up to four bytes in UTF-8, and one byte is required for the terminating
+/// zero.
+const MAX_IDENTIFIER_LEN: usize = 128 * 4 + 1;
+
+/// Kind of special columns retrieved by [`crate::Connection::special_columns`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum RowIdentifierType {
+    /// `SQL_BEST_ROWID`. The optimal column or set of columns that, by retrieving values from
+    /// them, allows any row in the table to be uniquely identified. A column can be either a
+    /// pseudo-column specifically designed for this purpose (e.g. `ROWID` in Oracle) or the
+    /// column(s) of a unique index.
+    BestRowId = 1,
+    /// `SQL_ROWVER`. The column(s) in the table, if any, that are automatically updated by the
+    /// data source when any value in the row is updated by any transaction (e.g. `ROWVERSION` in
+    /// Microsoft SQL Server).
+    RowVersion = 2,
+}
+
+/// Minimum required scope of the row identifier retrieved by
+/// [`crate::Connection::special_columns`]. Also reported in the `SCOPE` column of the result set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u16)]
+pub enum RowIdScope {
+    /// `SQL_SCOPE_CURROW`. The row id is guaranteed to be valid only while positioned on that
+    /// row. A later reselect using the row id may not return a row if the row was updated or
+    /// deleted by another transaction.
+    CurrentRow = 0,
+    /// `SQL_SCOPE_TRANSACTION`. The row id is guaranteed to be valid for the duration of the
+    /// current transaction.
+    Transaction = 1,
+    /// `SQL_SCOPE_SESSION`. The row id is guaranteed to be valid for the duration of the session
+    /// (across transaction boundaries).
+    Session = 2,
+}
+
+/// A row of the result set returned by [`crate::Connection::statistics`] or
+/// [`crate::Preallocated::statistics`]. Bind it to the cursor using [`crate::buffers::RowVec`].
+///
+/// Each row either describes a column of an index, or (if [`Self::statistics_type`] is
+/// [`Self::TABLE_STAT`]) statistics of the table itself.
+///
+/// # Example
+///
+/// ```no_run
+/// use odbc_api::{buffers::RowVec, Connection, Cursor, Error, StatisticsRow};
+///
+/// /// Print the unique indexes of a table, together with the columns they consist of.
+/// fn print_unique_indexes(conn: &Connection<'_>, table: &str) -> Result<(), Error> {
+///     let cursor = conn.statistics("", "", table, true, false)?;
+///     let mut block_cursor = cursor.bind_buffer(RowVec::<StatisticsRow>::new(100))?;
+///     while let Some(batch) = block_cursor.fetch()? {
+///         for row in batch.iter().filter(|row| !row.is_table_statistic()) {
+///             let index = row.index_name.as_str().unwrap().unwrap_or_default();
+///             let column = row.column_name.as_str().unwrap().unwrap_or_default();
+///             println!("{index}: {column}");
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct StatisticsRow {
+    /// `TABLE_CAT`. Catalog name of the table. `NULL` if not applicable to the data source.
+    pub catalog: VarCharArray<MAX_IDENTIFIER_LEN>,
+    /// `TABLE_SCHEM`. Schema name of the table. `NULL` if not applicable to the data source.
+    pub schema: VarCharArray<MAX_IDENTIFIER_LEN>,
+    /// `TABLE_NAME`. Name of the table.
+    pub table: VarCharArray<MAX_IDENTIFIER_LEN>,
+    /// `NON_UNIQUE`. `1` if the index allows duplicate values, `0` if the index values must be
+    /// unique. `NULL` for table statistics.
+    pub non_unique: Nullable<i16>,
+    /// `INDEX_QUALIFIER`. Identifier used to qualify the index name for a `DROP INDEX`. `NULL` if
+    /// not supported by the data source or for table statistics.
+    pub index_qualifier: VarCharArray<MAX_IDENTIFIER_LEN>,
+    /// `INDEX_NAME`. `NULL` for table statistics.
+    pub index_name: VarCharArray<MAX_IDENTIFIER_LEN>,
+    /// `TYPE`. One of [`Self::TABLE_STAT`], [`Self::INDEX_CLUSTERED`], [`Self::INDEX_HASHED`] or
+    /// [`Self::INDEX_OTHER`].
+    pub statistics_type: i16,
+    /// `ORDINAL_POSITION`. One based position of the column within the index. `NULL` for table
+    /// statistics.
+    pub ordinal_position: Nullable<i16>,
+    /// `COLUMN_NAME`. Name of the indexed column or an expression if the index is based on one.
+    /// `NULL` for table statistics.
+    pub column_name: VarCharArray<MAX_IDENTIFIER_LEN>,
+    /// `ASC_OR_DESC`. `A` for ascending, `D` for descending sort order. `NULL` if not supported by
+    /// the data source or for table statistics.
+    pub asc_or_desc: VarCharArray<2>,
+    /// `CARDINALITY`. Number of rows in the table for table statistics. Number of unique values in
+    /// the index otherwise. `NULL` if not available.
+    pub cardinality: Nullable<i32>,
+    /// `PAGES`. Number of pages used to store the table or the index. `NULL` if not available.
+    pub pages: Nullable<i32>,
+    /// `FILTER_CONDITION`. Filter condition of a filtered index. `NULL` if there is none, or it can
+    /// not be determined.
+    pub filter_condition: VarCharArray<256>,
+}
+
+impl StatisticsRow {
+    /// `SQL_TABLE_STAT`. The row contains statistics of the table itself.
+    pub const TABLE_STAT: i16 = 0;
+    /// `SQL_INDEX_CLUSTERED`. The row describes a column of a clustered index.
+    pub const INDEX_CLUSTERED: i16 = 1;
+    /// `SQL_INDEX_HASHED`. The row describes a column of a hashed index.
+    pub const INDEX_HASHED: i16 = 2;
+    /// `SQL_INDEX_OTHER`. The row describes a column of another type of index.
+    pub const INDEX_OTHER: i16 = 3;
+
+    /// `true` if the row contains statistics of the table rather than describing an index.
+    pub fn is_table_statistic(&self) -> bool {
+        self.statistics_type == Self::TABLE_STAT
+    }
+
+    /// `Some(true)` if the index values must be unique. `None` for table statistics.
+    pub fn is_unique(&self) -> Option<bool> {
+        self.non_unique.into_opt().map(|non_unique| non_unique == 0)
+    }
+
+    /// `Some(true)` if the index column is sorted in descending order. `None` if the sort order is
+    /// not known.
+    pub fn is_descending(&self) -> Option<bool> {
+        match self.asc_or_desc.as_bytes() {
+            Some(b"A") => Some(false),
+            Some(b"D") => Some(true),
+            _ => None,
+        }
+    }
+}
+
+unsafe impl FetchRow for StatisticsRow {
+    unsafe fn bind_columns_to_cursor(&mut self, mut cursor: StatementRef<'_>) -> Result<(), Error> {
+        self.catalog.bind_to_col(1, &mut cursor)?;
+        self.schema.bind_to_col(2, &mut cursor)?;
+        self.table.bind_to_col(3, &mut cursor)?;
+        self.non_unique.bind_to_col(4, &mut cursor)?;
+        self.index_qualifier.bind_to_col(5, &mut cursor)?;
+        self.index_name.bind_to_col(6, &mut cursor)?;
+        self.statistics_type.bind_to_col(7, &mut cursor)?;
+        self.ordinal_position.bind_to_col(8, &mut cursor)?;
+        self.column_name.bind_to_col(9, &mut cursor)?;
+        self.asc_or_desc.bind_to_col(10, &mut cursor)?;
+        self.cardinality.bind_to_col(11, &mut cursor)?;
+        self.pages.bind_to_col(12, &mut cursor)?;
+        self.filter_condition.bind_to_col(13, &mut cursor)?;
+        Ok(())
+    }
+
+    fn find_truncation(&self) -> Option<TruncationInfo> {
+        self.catalog
+            .find_truncation(0)
+            .or_else(|| self.schema.find_truncation(1))
+            .or_else(|| self.table.find_truncation(2))
+            .or_else(|| self.non_unique.find_truncation(3))
+            .or_else(|| self.index_qualifier.find_truncation(4))
+            .or_else(|| self.index_name.find_truncation(5))
+            .or_else(|| self.statistics_type.find_truncation(6))
+            .or_else(|| self.ordinal_position.find_truncation(7))
+            .or_else(|| self.column_name.find_truncation(8))
+            .or_else(|| self.asc_or_desc.find_truncation(9))
+            .or_else(|| self.cardinality.find_truncation(10))
+            .or_else(|| self.pages.find_truncation(11))
+            .or_else(|| self.filter_condition.find_truncation(12))
+    }
+
+    fn column_count() -> u16 {
+        13
+    }
+}
+
+/// A row of the result set returned by [`crate::Connection::procedure_columns`] or
+/// [`crate::Preallocated::procedure_columns`]. Bind it to the cursor using
+/// [`crate::buffers::RowVec`].
+///
+/// Each row describes either a parameter of a procedure, its return value or a column of the
+/// result set it returns. See [`Self::column_type`].
+///
+/// # Example
+///
+/// ```no_run
+/// use odbc_api::{buffers::RowVec, Connection, Cursor, Error, ProcedureColumnRow};
+///
+/// /// Names of the parameters of a procedure the application has to bind as output parameters.
+/// fn output_parameters(conn: &Connection<'_>, procedure: &str) -> Result<Vec<String>, Error> {
+///     let cursor = conn.procedure_columns("", "", procedure, "")?;
+///     let mut block_cursor = cursor.bind_buffer(RowVec::<ProcedureColumnRow>::new(100))?;
+///     let mut names = Vec::new();
+///     while let Some(batch) = block_cursor.fetch()? {
+///         for row in batch.iter().filter(|row| row.is_output()) {
+///             names.push(row.column_name.as_str().unwrap().unwrap_or_default().to_owned());
+///         }
+///     }
+///     Ok(names)
+/// }
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct ProcedureColumnRow {
+    /// `PROCEDURE_CAT`. Catalog name of the procedure. `NULL` if not applicable to the data source.
+    pub catalog: VarCharArray<MAX_IDENTIFIER_LEN>,
+    /// `PROCEDURE_SCHEM`. Schema name of the procedure. `NULL` if not applicable to the data
+    /// source.
+    pub schema: VarCharArray<MAX_IDENTIFIER_LEN>,
+    /// `PROCEDURE_NAME`. Name of the procedure.
+    pub procedure: VarCharArray<MAX_IDENTIFIER_LEN>,
+    /// `COLUMN_NAME`. Name of the parameter or result set column. Empty if it has no name.
+    pub column_name: VarCharArray<MAX_IDENTIFIER_LEN>,
+    /// `COLUMN_TYPE`. One of [`Self::PARAM_TYPE_UNKNOWN`], [`Self::PARAM_INPUT`],
+    /// [`Self::PARAM_INPUT_OUTPUT`], [`Self::RESULT_COL`], [`Self::PARAM_OUTPUT`] or
+    /// [`Self::RETURN_VALUE`].
+    pub column_type: i16,
+    /// `DATA_TYPE`. SQL data type, e.g. `SQL_INTEGER` (`4`). See [`crate::sys::SqlDataType`].
+    pub data_type: i16,
+    /// `TYPE_NAME`. Data source dependent name of the data type.
+    pub type_name: VarCharArray<MAX_IDENTIFIER_LEN>,
+    /// `COLUMN_SIZE`. Column size of the parameter or column. `NULL` if not applicable.
+    pub column_size: Nullable<i32>,
+    /// `BUFFER_LENGTH`. Length in bytes of the data transferred in its default C type.
+    pub buffer_length: Nullable<i32>,
+    /// `DECIMAL_DIGITS`. `NULL` for data types where decimal digits are not applicable.
+    pub decimal_digits: Nullable<i16>,
+    /// `NUM_PREC_RADIX`. Either `10` or `2` for numeric data types, `NULL` otherwise.
+    pub num_prec_radix: Nullable<i16>,
+    /// `NULLABLE`. `SQL_NO_NULLS` (`0`), `SQL_NULLABLE` (`1`) or `SQL_NULLABLE_UNKNOWN` (`2`).
+    pub nullable: i16,
+    /// `REMARKS`. Description of the parameter or column.
+    pub remarks: VarCharArray<256>,
+    /// `COLUMN_DEF`. Default value of the parameter. `NULL` if no default value is specified.
+    pub column_def: VarCharArray<256>,
+    /// `SQL_DATA_TYPE`. Like [`Self::data_type`], except for datetime and interval data types.
+    pub sql_data_type: i16,
+    /// `SQL_DATETIME_SUB`. Subtype code for datetime and interval data types. `NULL` otherwise.
+    pub sql_datetime_sub: Nullable<i16>,
+    /// `CHAR_OCTET_LENGTH`. Maximum length in bytes of character or binary data types. `NULL`
+    /// otherwise.
+    pub char_octet_length: Nullable<i32>,
+    /// `ORDINAL_POSITION`. One based position of the parameter in the procedure definition, or of
+    /// the column in the result set. `0` for the return value.
+    pub ordinal_position: i32,
+    /// `IS_NULLABLE`. `NO`, `YES` or an empty string if nullability is unknown.
+    pub is_nullable: VarCharArray<4>,
+}
+
+impl ProcedureColumnRow {
+    /// `SQL_PARAM_TYPE_UNKNOWN`. The column is a parameter whose type is unknown.
+    pub const PARAM_TYPE_UNKNOWN: i16 = 0;
+    /// `SQL_PARAM_INPUT`. The column is an input parameter.
+    pub const PARAM_INPUT: i16 = 1;
+    /// `SQL_PARAM_INPUT_OUTPUT`. The column is an input / output parameter.
+    pub const PARAM_INPUT_OUTPUT: i16 = 2;
+    /// `SQL_RESULT_COL`. The column is a column of the result set.
+    pub const RESULT_COL: i16 = 3;
+    /// `SQL_PARAM_OUTPUT`. The column is an output parameter.
+    pub const PARAM_OUTPUT: i16 = 4;
+    /// `SQL_RETURN_VALUE`. The column is the return value of the procedure.
+    pub const RETURN_VALUE: i16 = 5;
+
+    /// `true` if the row describes a parameter the application binds a value to, i.e. an input or
+    /// an input / output parameter. See [`crate::InOut`].
+    pub fn is_input(&self) -> bool {
+        matches!(
+            self.column_type,
+            Self::PARAM_INPUT | Self::PARAM_INPUT_OUTPUT
+        )
+    }
+
+    /// `true` if the row describes a parameter the driver writes a value to, i.e. an output or an
+    /// input / output parameter or the return value. See [`crate::Out`] and [`crate::InOut`].
+    pub fn is_output(&self) -> bool {
+        matches!(
+            self.column_type,
+            Self::PARAM_OUTPUT | Self::PARAM_INPUT_OUTPUT | Self::RETURN_VALUE
+        )
+    }
+}
+
+unsafe impl FetchRow for ProcedureColumnRow {
+    unsafe fn bind_columns_to_cursor(&mut self, mut cursor: StatementRef<'_>) -> Result<(), Error> {
+        self.catalog.bind_to_col(1, &mut cursor)?;
+        self.schema.bind_to_col(2, &mut cursor)?;
+        self.procedure.bind_to_col(3, &mut cursor)?;
+        self.column_name.bind_to_col(4, &mut cursor)?;
+        self.column_type.bind_to_col(5, &mut cursor)?;
+        self.data_type.bind_to_col(6, &mut cursor)?;
+        self.type_name.bind_to_col(7, &mut cursor)?;
+        self.column_size.bind_to_col(8, &mut cursor)?;
+        self.buffer_length.bind_to_col(9, &mut cursor)?;
+        self.decimal_digits.bind_to_col(10, &mut cursor)?;
+        self.num_prec_radix.bind_to_col(11, &mut cursor)?;
+        self.nullable.bind_to_col(12, &mut cursor)?;
+        self.remarks.bind_to_col(13, &mut cursor)?;
+        self.column_def.bind_to_col(14, &mut cursor)?;
+        self.sql_data_type.bind_to_col(15, &mut cursor)?;
+        self.sql_datetime_sub.bind_to_col(16, &mut cursor)?;
+        self.char_octet_length.bind_to_col(17, &mut cursor)?;
+        self.ordinal_position.bind_to_col(18, &mut cursor)?;
+        self.is_nullable.bind_to_col(19, &mut cursor)?;
+        Ok(())
+    }
+
+    fn find_truncation(&self) -> Option<TruncationInfo> {
+        self.catalog
+            .find_truncation(0)
+            .or_else(|| self.schema.find_truncation(1))
+            .or_else(|| self.procedure.find_truncation(2))
+            .or_else(|| self.column_name.find_truncation(3))
+            .or_else(|| self.column_type.find_truncation(4))
+            .or_else(|| self.data_type.find_truncation(5))
+            .or_else(|| self.type_name.find_truncation(6))
+            .or_else(|| self.column_size.find_truncation(7))
+            .or_else(|| self.buffer_length.find_truncation(8))
+            .or_else(|| self.decimal_digits.find_truncation(9))
+            .or_else(|| self.num_prec_radix.find_truncation(10))
+            .or_else(|| self.nullable.find_truncation(11))
+            .or_else(|| self.remarks.find_truncation(12))
+            .or_else(|| self.column_def.find_truncation(13))
+            .or_else(|| self.sql_data_type.find_truncation(14))
+            .or_else(|| self.sql_datetime_sub.find_truncation(15))
+            .or_else(|| self.char_octet_length.find_truncation(16))
+            .or_else(|| self.ordinal_position.find_truncation(17))
+            .or_else(|| self.is_nullable.find_truncation(18))
+    }
+
+    fn column_count() -> u16 {
+        19
+    }
+}
+
+/// A record of the result set of a catalog function, which can be read from a [`CursorRow`] using
+/// `SQLGetData`. Implemented e.g. by [`TableInfo`], [`ColumnInfo`] and [`ForeignKeyInfo`]. See
+/// [`CatalogIter`].
+pub trait CatalogRecord: Sized {
+    /// Reads the record from the current row of a result set with `num_cols` columns. Columns
+    /// beyond `num_cols` are not read, since drivers conforming to older versions of the ODBC
+    /// standard may report fewer columns than specified by ODBC 3.x.
+    fn read(row: &mut CursorRow<'_>, num_cols: u16) -> Result<Self, Error>;
+}
+
+/// Iterates over the result set of a catalog function and yields a typed record for each row. The
+/// values are fetched using `SQLGetData`, so identifiers and remarks are never truncated,
+/// independent of the column sizes reported by the driver.
+///
+/// Created by e.g. [`crate::Connection::table_infos`], [`crate::Connection::column_infos`] or
+/// [`crate::Connection::foreign_key_infos`].
+pub struct CatalogIter<C, R> {
+    cursor: C,
+    num_cols: u16,
+    record: PhantomData<R>,
+}
+
+impl<C, R> CatalogIter<C, R>
+where
+    C: Cursor,
+{
+    /// Wraps the cursor returned by a catalog function, e.g. [`crate::Preallocated::tables`]. It is
+    /// the callers responsibility to pick a record type matching the catalog function.
+    pub fn new(mut cursor: C) -> Result<Self, Error> {
+        let num_cols = cursor.num_result_cols()?.try_into().unwrap();
+        Ok(Self {
+            cursor,
+            num_cols,
+            record: PhantomData,
+        })
+    }
+
+    /// Releases ownership of the underlying cursor.
+    pub fn into_cursor(self) -> C {
+        self.cursor
+    }
+}
+
+impl<C, R> Iterator for CatalogIter<C, R>
+where
+    C: Cursor,
+    R: CatalogRecord,
+{
+    type Item = Result<R, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.cursor.next_row() {
+            Ok(Some(mut row)) => Some(R::read(&mut row, self.num_cols)),
+            Ok(None) => None,
+            Err(error) => Some(Err(error)),
+        }
+    }
+}
+
+/// A table, view or other object listed by [`crate::Connection::table_infos`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableInfo {
+    /// `TABLE_CAT`. `None` if not applicable to the data source.
+    pub catalog: Option<String>,
+    /// `TABLE_SCHEM`. `None` if not applicable to the data source.
+    pub schema: Option<String>,
+    /// `TABLE_NAME`.
+    pub name: String,
+    /// `TABLE_TYPE`. E.g. `TABLE`, `VIEW` or `SYSTEM TABLE`.
+    pub table_type: String,
+    /// `REMARKS`. Description of the table.
+    pub remarks: Option<String>,
+}
+
+impl CatalogRecord for TableInfo {
+    fn read(row: &mut CursorRow<'_>, _num_cols: u16) -> Result<Self, Error> {
+        Ok(TableInfo {
+            catalog: text(row, 1)?,
+            schema: text(row, 2)?,
+            name: text(row, 3)?.unwrap_or_default(),
+            table_type: text(row, 4)?.unwrap_or_default(),
+            remarks: text(row, 5)?,
+        })
+    }
+}
+
+/// A column of a table listed by [`crate::Connection::column_infos`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColumnInfo {
+    /// `TABLE_CAT`. `None` if not applicable to the data source.
+    pub catalog: Option<String>,
+    /// `TABLE_SCHEM`. `None` if not applicable to the data source.
+    pub schema: Option<String>,
+    /// `TABLE_NAME`.
+    pub table: String,
+    /// `COLUMN_NAME`.
+    pub name: String,
+    /// `DATA_TYPE`, `COLUMN_SIZE` and `DECIMAL_DIGITS`.
+    pub data_type: DataType,
+    /// `TYPE_NAME`. Data source dependent name of the type, e.g. `NVARCHAR` or `int8`.
+    pub type_name: String,
+    /// `NULLABLE`.
+    pub nullability: Nullability,
+    /// `REMARKS`. Description of the column.
+    pub remarks: Option<String>,
+    /// `COLUMN_DEF`. Default value of the column, e.g. a literal, `NULL` or a function like
+    /// `CURRENT_TIMESTAMP`. `None` if no default value has been specified.
+    pub default_value: Option<String>,
+    /// `ORDINAL_POSITION`. One based position of the column within the table. `None` if not
+    /// reported by the driver.
+    pub ordinal_position: Option<u16>,
+}
+
+impl CatalogRecord for ColumnInfo {
+    fn read(row: &mut CursorRow<'_>, num_cols: u16) -> Result<Self, Error> {
+        let catalog = text(row, 1)?;
+        let schema = text(row, 2)?;
+        let table = text(row, 3)?.unwrap_or_default();
+        let name = text(row, 4)?.unwrap_or_default();
+        let sql_data_type = small_int(row, 5)?.unwrap_or(0);
+        let type_name = text(row, 6)?.unwrap_or_default();
+        let column_size = integer(row, 7)?;
+        // Column 8 is `BUFFER_LENGTH`, which is of no interest for the application.
+        let decimal_digits = small_int(row, 9)?;
+        // Column 10 is `NUM_PREC_RADIX`, implied by the data type.
+        let nullable = small_int(row, 11)?.unwrap_or(2);
+        let remarks = text(row, 12)?;
+        // Columns from 13 onwards have been introduced with ODBC 3.0.
+        let default_value = if num_cols >= 13 { text(row, 13)? } else { None };
+        let ordinal_position = if num_cols >= 17 {
+            integer(row, 17)?.and_then(|pos| pos.try_into().ok())
+        } else {
+            None
+        };
+        Ok(ColumnInfo {
+            catalog,
+            schema,
+            table,
+            name,
+            data_type: DataType::new(
+                SqlDataType(sql_data_type),
+                // Some drivers report negative or no column sizes for types of unlimited length.
+                column_size
+                    .and_then(|size| size.try_into().ok())
+                    .unwrap_or(0),
+                decimal_digits.unwrap_or(0),
+            ),
+            type_name,
+            nullability: Nullability::new(odbc_sys::Nullability(nullable)),
+            remarks,
+            default_value,
+            ordinal_position,
+        })
+    }
+}
+
+/// A column of a foreign key listed by [`crate::Connection::foreign_key_infos`]. Foreign keys
+/// consisting of multiple columns are described by multiple records.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ForeignKeyInfo {
+    /// `PKTABLE_CAT`. Catalog of the referenced table. `None` if not applicable.
+    pub pk_catalog: Option<String>,
+    /// `PKTABLE_SCHEM`. Schema of the referenced table. `None` if not applicable.
+    pub pk_schema: Option<String>,
+    /// `PKTABLE_NAME`. Name of the referenced table.
+    pub pk_table: String,
+    /// `PKCOLUMN_NAME`. Name of the referenced column.
+    pub pk_column: String,
+    /// `FKTABLE_CAT`. Catalog of the table containing the foreign key. `None` if not applicable.
+    pub fk_catalog: Option<String>,
+    /// `FKTABLE_SCHEM`. Schema of the table containing the foreign key. `None` if not applicable.
+    pub fk_schema: Option<String>,
+    /// `FKTABLE_NAME`. Name of the table containing the foreign key.
+    pub fk_table: String,
+    /// `FKCOLUMN_NAME`. Name of the foreign key column.
+    pub fk_column: String,
+    /// `KEY_SEQ`. One based position of the column within the key.
+    pub key_seq: u16,
+    /// `UPDATE_RULE`. Action applied to the foreign key if the referenced key is updated. `None` if
+    /// not applicable to the data source.
+    pub update_rule: Option<ReferentialAction>,
+    /// `DELETE_RULE`. Action applied to the foreign key if the referenced row is deleted. `None` if
+    /// not applicable to the data source.
+    pub delete_rule: Option<ReferentialAction>,
+    /// `FK_NAME`. Name of the foreign key constraint. `None` if not applicable.
+    pub fk_name: Option<String>,
+    /// `PK_NAME`. Name of the referenced primary key. `None` if not applicable.
+    pub pk_name: Option<String>,
+}
+
+impl CatalogRecord for ForeignKeyInfo {
+    fn read(row: &mut CursorRow<'_>, _num_cols: u16) -> Result<Self, Error> {
+        Ok(ForeignKeyInfo {
+            pk_catalog: text(row, 1)?,
+            pk_schema: text(row, 2)?,
+            pk_table: text(row, 3)?.unwrap_or_default(),
+            pk_column: text(row, 4)?.unwrap_or_default(),
+            fk_catalog: text(row, 5)?,
+            fk_schema: text(row, 6)?,
+            fk_table: text(row, 7)?.unwrap_or_default(),
+            fk_column: text(row, 8)?.unwrap_or_default(),
+            key_seq: small_int(row, 9)?
+                .and_then(|seq| seq.try_into().ok())
+                .unwrap_or(0),
+            update_rule: small_int(row, 10)?.and_then(ReferentialAction::from_sql),
+            delete_rule: small_int(row, 11)?.and_then(ReferentialAction::from_sql),
+            fk_name: text(row, 12)?,
+            pk_name: text(row, 13)?,
+        })
+    }
+}
+
+/// Action applied to a foreign key, if the referenced key is updated or deleted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReferentialAction {
+    /// `SQL_CASCADE`. The foreign key is updated, or the row containing it is deleted.
+    Cascade,
+    /// `SQL_RESTRICT`. The update or deletion of the referenced key is rejected.
+    Restrict,
+    /// `SQL_SET_NULL`. The foreign key is set to `NULL`.
+    SetNull,
+    /// `SQL_NO_ACTION`. Like [`Self::Restrict`], yet the check may be deferred.
+    NoAction,
+    /// `SQL_SET_DEFAULT`. The foreign key is set to its default value.
+    SetDefault,
+}
+
+impl ReferentialAction {
+    fn from_sql(value: i16) -> Option<Self> {
+        match value {
+            0 => Some(ReferentialAction::Cascade),
+            1 => Some(ReferentialAction::Restrict),
+            2 => Some(ReferentialAction::SetNull),
+            3 => Some(ReferentialAction::NoAction),
+            4 => Some(ReferentialAction::SetDefault),
+            _ => None,
+        }
+    }
+}
+
+/// Reads a text column of a catalog result set. `None` if the value is `NULL`.
+pub(crate) fn text(row: &mut CursorRow<'_>, col: u16) -> Result<Option<String>, Error> {
+    // Ask for UTF-16 independent of the `narrow` feature, so we can decode the text without
+    // knowing the system encoding.
+    let mut buf = Vec::new();
+    let not_null = row.get_wide_text(col, &mut buf)?;
+    Ok(not_null.then(|| String::from_utf16_lossy(&buf)))
+}
+
+/// Reads a `SMALLINT` column of a catalog result set. `None` if the value is `NULL`.
+pub(crate) fn small_int(row: &mut CursorRow<'_>, col: u16) -> Result<Option<i16>, Error> {
+    let mut value = Nullable::<i16>::null();
+    row.get_data(col, &mut value)?;
+    Ok(value.into_opt())
+}
+
+/// Reads an `INTEGER` column of a catalog result set. `None` if the value is `NULL`.
+pub(crate) fn integer(row: &mut CursorRow<'_>, col: u16) -> Result<Option<i32>, Error> {
+    let mut value = Nullable::<i32>::null();
+    row.get_data(col, &mut value)?;
+    Ok(value.into_opt())
+}
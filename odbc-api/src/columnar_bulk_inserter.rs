@@ -1,7 +1,10 @@
 use crate::{
     buffers::{ColumnBuffer, TextColumn},
     execute::execute,
-    handles::{AsStatementRef, HasDataType, Statement, StatementRef},
+    handles::{
+        log_diagnostics, AsStatementRef, CDataMut, Diagnostics, HasDataType,
+        Record as DiagnosticRecord, SqlResult, Statement, StatementRef,
+    },
     CursorImpl, Error,
 };
 
@@ -42,6 +45,8 @@ where
     {
         let mut stmt = statement.as_stmt_ref();
         stmt.reset_parameters();
+        // The statement may have been used for row wise bulk inserts before.
+        stmt.set_param_bind_type(0).into_result(&stmt)?;
         let mut parameter_number = 1;
         // Bind buffers to statement.
         for column in &parameters {
@@ -90,6 +95,112 @@ where
         }
     }
 
+    /// Execute the prepared statement, with the parameters bound, and report the outcome for each
+    /// row of the batch individually. Contrary to [`Self::execute`], a batch in which only some
+    /// rows fail does not result in an error. Instead the failed rows and their diagnostics are
+    /// listed in the returned [`BatchReport`], so they can be set aside and the remaining rows can
+    /// be retried. An error is still returned for failures which are not specific to any row.
+    ///
+    /// The status of each row is retrieved by binding `SQL_ATTR_PARAM_STATUS_PTR` and
+    /// `SQL_ATTR_PARAMS_PROCESSED_PTR` for the duration of the execution. Diagnostics are matched
+    /// to rows using their `SQL_DIAG_ROW_NUMBER` field. Drivers which do not support parameter
+    /// arrays natively may report [`ParamStatus::DiagnosticsUnavailable`] for rows and no row
+    /// numbers for their diagnostics.
+    ///
+    /// This method is intended for statements which do not return result sets, like `INSERT`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error, buffers::BufferDesc};
+    ///
+    /// fn insert_and_report(conn: &Connection, years: &[i16]) -> Result<(), Error> {
+    ///     let prepared = conn.prepare("INSERT INTO Birthdays (year) VALUES (?)")?;
+    ///     let desc = [BufferDesc::I16 { nullable: false }];
+    ///     let mut inserter = prepared.into_column_inserter(years.len(), desc)?;
+    ///     inserter.set_num_rows(years.len());
+    ///     inserter.column_mut(0).as_slice::<i16>().unwrap().copy_from_slice(years);
+    ///     let report = inserter.execute_with_report()?;
+    ///     for failed in &report.failed_rows {
+    ///         eprintln!("Could not insert {}: {:?}", years[failed.row_index], failed.diagnostics);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn execute_with_report(&mut self) -> Result<BatchReport, Error> {
+        let num_rows = self.parameter_set_size;
+        if num_rows == 0 {
+            // Same as `execute` we do not execute anything for an empty batch.
+            return Ok(BatchReport {
+                params_processed: 0,
+                statuses: Vec::new(),
+                failed_rows: Vec::new(),
+            });
+        }
+        // Initialize with `SQL_PARAM_UNUSED`, in case the driver does not touch the array.
+        let mut param_status = vec![7u16; num_rows];
+        let mut params_processed = 0;
+        let mut stmt = self.statement.as_stmt_ref();
+        let outcome = unsafe {
+            stmt.set_paramset_size(num_rows);
+            stmt.set_param_status(&mut param_status)
+                .into_result(&stmt)?;
+            if let Err(error) = stmt
+                .set_params_processed(&mut params_processed)
+                .into_result(&stmt)
+            {
+                stmt.unset_param_status();
+                return Err(error);
+            }
+            let result = stmt.execute();
+            let row_diagnostics = match result {
+                SqlResult::SuccessWithInfo(()) | SqlResult::Error { .. } => {
+                    diagnostics_by_row(&stmt)
+                }
+                _ => Vec::new(),
+            };
+            let statuses: Vec<_> = param_status
+                .iter()
+                .copied()
+                .map(ParamStatus::from_raw)
+                .collect();
+            // Only fail the entire batch, if the error can not be attributed to individual rows.
+            let any_row_failed = statuses.contains(&ParamStatus::Error);
+            let outcome = match result {
+                SqlResult::Error { .. } if any_row_failed => {
+                    log_diagnostics(&stmt);
+                    Ok(())
+                }
+                // Statements like `UPDATE` or `DELETE` may affect no rows at all.
+                other => other.into_result_with(&stmt, Some(()), None),
+            };
+            outcome.map(|()| (statuses, row_diagnostics))
+        };
+        // Unbind the status pointers again, since they point to local variables.
+        stmt.unset_param_status().into_result(&stmt)?;
+        stmt.unset_params_processed().into_result(&stmt)?;
+        let (statuses, mut row_diagnostics) = outcome?;
+
+        let failed_rows = statuses
+            .iter()
+            .enumerate()
+            .filter(|(_, status)| **status == ParamStatus::Error)
+            .map(|(row_index, _)| FailedRow {
+                row_index,
+                diagnostics: row_diagnostics
+                    .iter_mut()
+                    .filter(|(row, _)| *row == row_index)
+                    .map(|(_, record)| std::mem::take(record))
+                    .collect(),
+            })
+            .collect();
+        Ok(BatchReport {
+            params_processed,
+            statuses,
+            failed_rows,
+        })
+    }
+
     /// Sets the number of rows in the buffer to zero.
     pub fn clear(&mut self) {
         self.parameter_set_size = 0;
@@ -224,6 +335,121 @@ pub unsafe trait BoundInputSlice<'a> {
     ) -> Self::SliceMut;
 }
 
+/// Outcome of executing a batch using [`ColumnarBulkInserter::execute_with_report`].
+#[derive(Debug)]
+pub struct BatchReport {
+    /// Number of rows processed by the data source, including the failed ones. May be smaller than
+    /// the number of rows in the batch, if the driver stopped processing the batch after an error.
+    pub params_processed: usize,
+    /// Status of each row in the batch, as reported by the driver.
+    pub statuses: Vec<ParamStatus>,
+    /// Rows of the batch which could not be executed, ordered by their index.
+    pub failed_rows: Vec<FailedRow>,
+}
+
+/// A row of a batch which could not be executed. See [`BatchReport`].
+#[derive(Debug)]
+pub struct FailedRow {
+    /// Zero based index of the row in the batch.
+    pub row_index: usize,
+    /// Diagnostic records the driver associated with this row. May be empty, if the driver does
+    /// not report row numbers for its diagnostics.
+    pub diagnostics: Vec<DiagnosticRecord>,
+}
+
+/// Status of an individual row (parameter set) after executing a statement with an array of
+/// parameters. See [`BatchReport::statuses`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamStatus {
+    /// `SQL_PARAM_SUCCESS`. The statement has been executed successfully for this row.
+    Success,
+    /// `SQL_PARAM_SUCCESS_WITH_INFO`. The statement has been executed successfully for this row,
+    /// but the driver returned a warning.
+    SuccessWithInfo,
+    /// `SQL_PARAM_ERROR`. An error occurred executing the statement for this row.
+    Error,
+    /// `SQL_PARAM_UNUSED`. The row has not been processed, e.g. because the driver stopped after
+    /// an error in a previous row.
+    Unused,
+    /// `SQL_PARAM_DIAG_UNAVAILABLE`. The driver treats the array of parameters as a monolithic unit
+    /// and does not report errors for individual rows.
+    DiagnosticsUnavailable,
+    /// A value not defined by the ODBC standard, e.g. a driver specific parameter status.
+    Other(u16),
+}
+
+impl ParamStatus {
+    /// Converts the value written into the parameter status array by the driver. Values not
+    /// defined by the ODBC standard are mapped to [`ParamStatus::Other`].
+    pub fn from_raw(raw: u16) -> Self {
+        match raw {
+            0 => ParamStatus::Success,
+            1 => ParamStatus::DiagnosticsUnavailable,
+            5 => ParamStatus::Error,
+            6 => ParamStatus::SuccessWithInfo,
+            7 => ParamStatus::Unused,
+            other => ParamStatus::Other(other),
+        }
+    }
+}
+
+/// Collects all diagnostic records of the last function call on `stmt` which are associated with
+/// a row, together with the zero based index of that row.
+fn diagnostics_by_row(stmt: &StatementRef<'_>) -> Vec<(usize, DiagnosticRecord)> {
+    let mut row_diagnostics = Vec::new();
+    let mut rec_number = 1;
+    loop {
+        let mut record = DiagnosticRecord::with_capacity(512);
+        if !record.fill_from(stmt, rec_number) {
+            break;
+        }
+        // Row numbers are one based. Treat `0` like `SQL_NO_ROW_NUMBER`, rather than underflowing.
+        if let Some(row_index) = stmt
+            .diagnostic_row_number(rec_number)
+            .and_then(|row_number| row_number.checked_sub(1))
+        {
+            row_diagnostics.push((row_index, record));
+        }
+        // Diagnostic record numbers are 16Bit integers.
+        if rec_number == i16::MAX {
+            break;
+        }
+        rec_number += 1;
+    }
+    row_diagnostics
+}
+
+/// Where a buffer handed out as a mutable slice is bound to the statement. Needed to rebind the
+/// buffer, should it be reallocated.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum BoundAt {
+    /// One based index of an input parameter, e.g. in a [`ColumnarBulkInserter`].
+    Parameter(u16),
+    /// One based index of a result set column, e.g. in a [`crate::BlockCursor`].
+    Column(u16),
+}
+
+impl BoundAt {
+    /// Binds `buffer` to `stmt` again at the same position, after it has been reallocated.
+    ///
+    /// # Safety
+    ///
+    /// `buffer` must stay valid for as long as it is bound to the statement.
+    pub(crate) unsafe fn rebind(
+        self,
+        stmt: &mut StatementRef<'_>,
+        buffer: &mut (impl HasDataType + CDataMut),
+    ) -> Result<(), Error> {
+        match self {
+            BoundAt::Parameter(parameter_number) => {
+                stmt.bind_input_parameter(parameter_number, buffer)
+            }
+            BoundAt::Column(column_number) => stmt.bind_col(column_number, buffer),
+        }
+        .into_result(stmt)
+    }
+}
+
 impl<S> ColumnarBulkInserter<S, TextColumn<u8>> {
     /// Takes one element from the iterator for each internal column buffer and appends it to the
     /// end of the buffer. Should a cell of the row be too large for the associated column buffer,
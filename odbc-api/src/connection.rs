@@ -1,14 +1,19 @@
 use crate::{
     buffers::BufferDesc,
     execute::{
-        execute_columns, execute_foreign_keys, execute_tables, execute_with_parameters,
+        execute_column_privileges, execute_columns, execute_foreign_keys, execute_primary_keys,
+        execute_procedure_columns, execute_procedures, execute_special_columns, execute_statistics,
+        execute_table_privileges, execute_tables, execute_with_parameters,
         execute_with_parameters_polling,
     },
     handles::{self, slice_to_utf8, SqlText, State, Statement, StatementImpl},
+    sleep::wait_for_connection,
     statement_connection::StatementConnection,
-    CursorImpl, CursorPolling, Error, ParameterCollectionRef, Preallocated, Prepared, Sleep,
+    CatalogIter, ColumnInfo, ConnectionInfo, CursorImpl, CursorPolling, Error, ForeignKeyInfo,
+    ParameterCollectionRef, Preallocated, Prepared, RowIdScope, RowIdentifierType, Sleep,
+    StatementOptions, TableInfo, TableSchema, Transaction, TypeInfo,
 };
-use odbc_sys::HDbc;
+use odbc_sys::{HDbc, SqlDataType};
 use std::{
     borrow::Cow,
     fmt::{self, Debug, Display},
@@ -129,6 +134,39 @@ impl<'c> Connection<'c> {
         execute_with_parameters(lazy_statement, Some(&query), params)
     }
 
+    /// Like [`Self::execute`], but applies `options` to the statement before executing it. E.g.:
+    ///
+    /// ```
+    /// use odbc_api::{Connection, Error, StatementOptions};
+    ///
+    /// fn count_movies(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     let options = StatementOptions {
+    ///         query_timeout_sec: Some(5),
+    ///         ..StatementOptions::default()
+    ///     };
+    ///     match conn.execute_with_options("SELECT COUNT(*) FROM Movies", (), options) {
+    ///         Ok(_) => (),
+    ///         Err(Error::Timeout { .. }) => println!("Query took longer than five seconds."),
+    ///         Err(other) => return Err(other),
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn execute_with_options(
+        &self,
+        query: &str,
+        params: impl ParameterCollectionRef,
+        options: StatementOptions,
+    ) -> Result<Option<CursorImpl<StatementImpl<'_>>>, Error> {
+        let query = SqlText::new(query);
+        let lazy_statement = move || {
+            let mut stmt = self.allocate_statement()?;
+            options.apply(&mut stmt)?;
+            Ok(stmt)
+        };
+        execute_with_parameters(lazy_statement, Some(&query), params)
+    }
+
     /// Asynchronous sibling of [`Self::execute`]. Uses polling mode to be asynchronous. `sleep`
     /// does govern the behaviour of polling, by waiting for the future in between polling. Sleep
     /// should not be implemented using a sleep which blocks the system thread, but rather utilize
@@ -158,6 +196,10 @@ impl<'c> Connection<'c> {
     /// PostgerSQL, SQLite and MariaDB this worked only with Microsoft SQL Server. For code generic
     /// over every driver you may still use this. The functions will return with the correct results
     /// just be aware that may block until they are finished.
+    ///
+    /// Dropping the returned future while the statement is still executing cancels the execution
+    /// using `SQLCancel`. Since it is not possible to await in drop, dropping the future blocks the
+    /// current thread until the driver confirms the cancellation.
     pub async fn execute_polling(
         &self,
         query: &str,
@@ -275,6 +317,20 @@ impl<'c> Connection<'c> {
         Ok(Prepared::new(stmt))
     }
 
+    /// Like [`Self::prepare`], but applies `options` to the statement. The options stay in effect
+    /// for every execution of the prepared statement.
+    pub fn prepare_with_options(
+        &self,
+        query: &str,
+        options: StatementOptions,
+    ) -> Result<Prepared<StatementImpl<'_>>, Error> {
+        let query = SqlText::new(query);
+        let mut stmt = self.allocate_statement()?;
+        options.apply(&mut stmt)?;
+        stmt.prepare(&query).into_result(&stmt)?;
+        Ok(Prepared::new(stmt))
+    }
+
     /// Prepares an SQL statement which takes ownership of the connection. The advantage over
     /// [`Self::prepare`] is, that you do not need to keep track of the lifetime of the connection
     /// seperatly and can create types which do own the prepared query and only depend on the
@@ -378,6 +434,80 @@ impl<'c> Connection<'c> {
             .into_result(&self.connection)
     }
 
+    /// `true` if the connection is in auto-commit mode, `false` if it is in manual-commit mode. See
+    /// [`Connection::set_autocommit`].
+    pub fn is_autocommit(&self) -> Result<bool, Error> {
+        self.connection
+            .is_autocommit()
+            .into_result(&self.connection)
+    }
+
+    /// Start a transaction. The returned guard switches the connection into manual-commit mode.
+    /// Unless [`Transaction::commit`] is called, the transaction is rolled back then the guard is
+    /// dropped. Afterwards the previous commit mode of the connection is restored. See
+    /// [`Transaction`].
+    pub fn begin_transaction(&self) -> Result<Transaction<'_, 'c>, Error> {
+        Transaction::new(self)
+    }
+
+    /// `false` if the driver reports that the data source does not support transactions at all.
+    pub fn supports_transactions(&self) -> Result<bool, Error> {
+        // SQL_TC_NONE
+        const TRANSACTIONS_NOT_SUPPORTED: u16 = 0;
+        let capable = self
+            .connection
+            .transaction_capable()
+            .into_result(&self.connection)?;
+        Ok(capable != TRANSACTIONS_NOT_SUPPORTED)
+    }
+
+    /// Set the transaction isolation level of the connection. Must not be called while a
+    /// transaction is open. See also [`ConnectionOptions::isolation_level`] to set the isolation
+    /// level at connect time.
+    pub fn set_isolation_level(&self, level: IsolationLevel) -> Result<(), Error> {
+        self.connection
+            .set_transaction_isolation(level as u32)
+            .into_result(&self.connection)
+    }
+
+    /// Current transaction isolation level of the connection. `None` if the driver reports an
+    /// isolation level which is not defined by the ODBC standard.
+    pub fn isolation_level(&self) -> Result<Option<IsolationLevel>, Error> {
+        let value = self
+            .connection
+            .transaction_isolation()
+            .into_result(&self.connection)?;
+        Ok(IsolationLevel::from_sql(value))
+    }
+
+    /// Transaction isolation levels the data source advertises as supported, ordered from least to
+    /// most strict. Corresponds to the `SQL_TXN_ISOLATION_OPTION` information type.
+    pub fn supported_isolation_levels(&self) -> Result<Vec<IsolationLevel>, Error> {
+        let mask = self
+            .connection
+            .transaction_isolation_options()
+            .into_result(&self.connection)?;
+        Ok(IsolationLevel::ALL
+            .into_iter()
+            .filter(|level| mask & *level as u32 != 0)
+            .collect())
+    }
+
+    /// Switch the connection between read-only (`true`) and read-write (`false`) access mode.
+    /// Drivers use the access mode as a hint to optimize transactions, and are not required to
+    /// prevent statements from modifying the data source. See also
+    /// [`ConnectionOptions::read_only`].
+    pub fn set_read_only(&self, read_only: bool) -> Result<(), Error> {
+        self.connection
+            .set_read_only(read_only)
+            .into_result(&self.connection)
+    }
+
+    /// `true` if the connection is in read-only access mode.
+    pub fn is_read_only(&self) -> Result<bool, Error> {
+        self.connection.is_read_only().into_result(&self.connection)
+    }
+
     /// To commit a transaction in manual-commit mode.
     pub fn commit(&self) -> Result<(), Error> {
         self.connection.commit().into_result(&self.connection)
@@ -388,6 +518,42 @@ impl<'c> Connection<'c> {
         self.connection.rollback().into_result(&self.connection)
     }
 
+    /// Asynchronous sibling of [`Self::commit`]. Uses polling mode to be asynchronous. See
+    /// [`Self::execute_polling`] for how to use `sleep`.
+    ///
+    /// **Attention**: This feature requires driver support for asynchronous execution on
+    /// connection level (`SQL_ATTR_ASYNC_DBC_FUNCTIONS_ENABLE`). If the driver does not support it,
+    /// or the `odbc_version_3_80` feature is not active, the transaction is committed blocking.
+    ///
+    /// Takes `&mut self`, since no other function may be called on the connection while an
+    /// asynchronous function is still executing.
+    pub async fn commit_polling(&mut self, mut sleep: impl Sleep) -> Result<(), Error> {
+        wait_for_connection(
+            &mut self.connection,
+            |connection| connection.commit(),
+            &mut sleep,
+        )
+        .await
+    }
+
+    /// Asynchronous sibling of [`Self::rollback`]. Uses polling mode to be asynchronous. See
+    /// [`Self::execute_polling`] for how to use `sleep`.
+    ///
+    /// **Attention**: This feature requires driver support for asynchronous execution on
+    /// connection level (`SQL_ATTR_ASYNC_DBC_FUNCTIONS_ENABLE`). If the driver does not support it,
+    /// or the `odbc_version_3_80` feature is not active, the transaction is rolled back blocking.
+    ///
+    /// Takes `&mut self`, since no other function may be called on the connection while an
+    /// asynchronous function is still executing.
+    pub async fn rollback_polling(&mut self, mut sleep: impl Sleep) -> Result<(), Error> {
+        wait_for_connection(
+            &mut self.connection,
+            |connection| connection.rollback(),
+            &mut sleep,
+        )
+        .await
+    }
+
     /// Indicates the state of the connection. If `true` the connection has been lost. If `false`,
     /// the connection is still active.
     pub fn is_dead(&self) -> Result<bool, Error> {
@@ -409,6 +575,13 @@ impl<'c> Connection<'c> {
         Ok(name)
     }
 
+    /// Capabilities of the driver and the data source, like DBMS and driver versions, supported
+    /// SQL conformance level or batch support. Allows adapting to the backend without parsing the
+    /// name of the database management system.
+    pub fn info(&self) -> Result<ConnectionInfo, Error> {
+        ConnectionInfo::fetch(&self.connection)
+    }
+
     /// Maximum length of catalog names.
     pub fn max_catalog_name_len(&self) -> Result<u16, Error> {
         self.connection
@@ -573,6 +746,317 @@ impl<'c> Connection<'c> {
         )
     }
 
+    /// Retrieves the optimal set of columns that uniquely identifies a row in the table
+    /// ([`RowIdentifierType::BestRowId`]), or the columns that are automatically updated when any
+    /// value in the row is updated by a transaction ([`RowIdentifierType::RowVersion`]). The
+    /// returned cursor has the columns: `SCOPE`, `COLUMN_NAME`, `DATA_TYPE`, `TYPE_NAME`,
+    /// `COLUMN_SIZE`, `BUFFER_LENGTH`, `DECIMAL_DIGITS`, `PSEUDO_COLUMN`.
+    ///
+    /// The arguments are not search patterns. Empty strings are passed as `NULL` to the driver, i.e.
+    /// an empty `catalog_name` or `schema_name` does not restrict the result. `table_name` must not
+    /// be empty.
+    ///
+    /// * `scope`: Minimum required scope of the row identifier.
+    /// * `nullable`: If `true` special columns which can have `NULL` values are returned, otherwise
+    ///   they are excluded.
+    ///
+    /// See: <https://learn.microsoft.com/sql/odbc/reference/syntax/sqlspecialcolumns-function>
+    pub fn special_columns(
+        &self,
+        identifier_type: RowIdentifierType,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+        scope: RowIdScope,
+        nullable: bool,
+    ) -> Result<CursorImpl<StatementImpl<'_>>, Error> {
+        let statement = self.allocate_statement()?;
+
+        execute_special_columns(
+            statement,
+            identifier_type,
+            &SqlText::new(catalog_name),
+            &SqlText::new(schema_name),
+            &SqlText::new(table_name),
+            scope,
+            nullable,
+        )
+    }
+
+    /// A cursor describing the privileges on the tables matching the patterns. `schema_name` and
+    /// `table_name` support as placeholder `%` for multiple characters or `_` for a single
+    /// character. Use `\` to escape. Empty strings do not restrict the result. The returned cursor
+    /// has the columns: `TABLE_CAT`, `TABLE_SCHEM`, `TABLE_NAME`, `GRANTOR`, `GRANTEE`,
+    /// `PRIVILEGE`, `IS_GRANTABLE`.
+    ///
+    /// See: <https://learn.microsoft.com/sql/odbc/reference/syntax/sqltableprivileges-function>
+    pub fn table_privileges(
+        &self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+    ) -> Result<CursorImpl<StatementImpl<'_>>, Error> {
+        let statement = self.allocate_statement()?;
+
+        execute_table_privileges(
+            statement,
+            &SqlText::new(catalog_name),
+            &SqlText::new(schema_name),
+            &SqlText::new(table_name),
+        )
+    }
+
+    /// A cursor describing the privileges on the columns of a table. `column_name` supports as
+    /// placeholder `%` for multiple characters or `_` for a single character. Use `\` to escape.
+    /// The other arguments are not search patterns. Empty strings do not restrict the result. The
+    /// returned cursor has the columns: `TABLE_CAT`, `TABLE_SCHEM`, `TABLE_NAME`, `COLUMN_NAME`,
+    /// `GRANTOR`, `GRANTEE`, `PRIVILEGE`, `IS_GRANTABLE`.
+    ///
+    /// See: <https://learn.microsoft.com/sql/odbc/reference/syntax/sqlcolumnprivileges-function>
+    pub fn column_privileges(
+        &self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+        column_name: &str,
+    ) -> Result<CursorImpl<StatementImpl<'_>>, Error> {
+        let statement = self.allocate_statement()?;
+
+        execute_column_privileges(
+            statement,
+            &SqlText::new(catalog_name),
+            &SqlText::new(schema_name),
+            &SqlText::new(table_name),
+            &SqlText::new(column_name),
+        )
+    }
+
+    /// Returns the column names that make up the primary key for a table. The returned cursor has
+    /// the columns: `TABLE_CAT`, `TABLE_SCHEM`, `TABLE_NAME`, `COLUMN_NAME`, `KEY_SEQ`, `PK_NAME`.
+    /// The result set is ordered by `TABLE_CAT`, `TABLE_SCHEM`, `TABLE_NAME` and `KEY_SEQ`.
+    ///
+    /// The arguments are not search patterns. Empty strings are passed as `NULL` to the driver, i.e.
+    /// an empty `catalog_name` or `schema_name` does not restrict the result. `table_name` must not
+    /// be empty.
+    ///
+    /// See: <https://learn.microsoft.com/sql/odbc/reference/syntax/sqlprimarykeys-function>
+    pub fn primary_keys(
+        &self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+    ) -> Result<CursorImpl<StatementImpl<'_>>, Error> {
+        let statement = self.allocate_statement()?;
+
+        execute_primary_keys(
+            statement,
+            &SqlText::new(catalog_name),
+            &SqlText::new(schema_name),
+            &SqlText::new(table_name),
+        )
+    }
+
+    /// Retrieves statistics about a single table and the indexes associated with it. The returned
+    /// cursor has the columns: `TABLE_CAT`, `TABLE_SCHEM`, `TABLE_NAME`, `NON_UNIQUE`,
+    /// `INDEX_QUALIFIER`, `INDEX_NAME`, `TYPE`, `ORDINAL_POSITION`, `COLUMN_NAME`, `ASC_OR_DESC`,
+    /// `CARDINALITY`, `PAGES`, `FILTER_CONDITION`. Use [`crate::StatisticsRow`] to fetch the result
+    /// set row by row into typed rows.
+    ///
+    /// The arguments are not search patterns. Empty strings are passed as `NULL` to the driver, i.e.
+    /// an empty `catalog_name` or `schema_name` does not restrict the result. `table_name` must not
+    /// be empty.
+    ///
+    /// * `unique_only`: If `true` only unique indexes are returned, otherwise all indexes.
+    /// * `ensure_accuracy`: If `true` the driver retrieves `CARDINALITY` and `PAGES`
+    ///   unconditionally, otherwise only if they are readily available.
+    ///
+    /// See: <https://learn.microsoft.com/sql/odbc/reference/syntax/sqlstatistics-function>
+    pub fn statistics(
+        &self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+        unique_only: bool,
+        ensure_accuracy: bool,
+    ) -> Result<CursorImpl<StatementImpl<'_>>, Error> {
+        let statement = self.allocate_statement()?;
+
+        execute_statistics(
+            statement,
+            &SqlText::new(catalog_name),
+            &SqlText::new(schema_name),
+            &SqlText::new(table_name),
+            unique_only,
+            ensure_accuracy,
+        )
+    }
+
+    /// A cursor describing the procedures matching the patterns. `schema_name` and `procedure_name`
+    /// support as placeholder `%` for multiple characters or `_` for a single character. Use `\`
+    /// to escape. Empty strings do not restrict the result. The returned cursor has the columns:
+    /// `PROCEDURE_CAT`, `PROCEDURE_SCHEM`, `PROCEDURE_NAME`, `NUM_INPUT_PARAMS`,
+    /// `NUM_OUTPUT_PARAMS`, `NUM_RESULT_SETS`, `REMARKS`, `PROCEDURE_TYPE`.
+    ///
+    /// See: <https://learn.microsoft.com/sql/odbc/reference/syntax/sqlprocedures-function>
+    pub fn procedures(
+        &self,
+        catalog_name: &str,
+        schema_name: &str,
+        procedure_name: &str,
+    ) -> Result<CursorImpl<StatementImpl<'_>>, Error> {
+        let statement = self.allocate_statement()?;
+
+        execute_procedures(
+            statement,
+            &SqlText::new(catalog_name),
+            &SqlText::new(schema_name),
+            &SqlText::new(procedure_name),
+        )
+    }
+
+    /// A cursor describing the parameters and result set columns of the procedures matching the
+    /// patterns. `schema_name`, `procedure_name` and `column_name` support as placeholder `%` for
+    /// multiple characters or `_` for a single character. Use `\` to escape. Empty strings do not
+    /// restrict the result. The returned cursor has the columns: `PROCEDURE_CAT`,
+    /// `PROCEDURE_SCHEM`, `PROCEDURE_NAME`, `COLUMN_NAME`, `COLUMN_TYPE`, `DATA_TYPE`, `TYPE_NAME`,
+    /// `COLUMN_SIZE`, `BUFFER_LENGTH`, `DECIMAL_DIGITS`, `NUM_PREC_RADIX`, `NULLABLE`, `REMARKS`,
+    /// `COLUMN_DEF`, `SQL_DATA_TYPE`, `SQL_DATETIME_SUB`, `CHAR_OCTET_LENGTH`, `ORDINAL_POSITION`,
+    /// `IS_NULLABLE`. Use [`crate::ProcedureColumnRow`] to fetch the result set row by row into
+    /// typed rows, e.g. in order to check the parameter modes of a procedure.
+    ///
+    /// In addition to that there may be a number of columns specific to the data source.
+    ///
+    /// See: <https://learn.microsoft.com/sql/odbc/reference/syntax/sqlprocedurecolumns-function>
+    pub fn procedure_columns(
+        &self,
+        catalog_name: &str,
+        schema_name: &str,
+        procedure_name: &str,
+        column_name: &str,
+    ) -> Result<CursorImpl<StatementImpl<'_>>, Error> {
+        let statement = self.allocate_statement()?;
+
+        execute_procedure_columns(
+            statement,
+            &SqlText::new(catalog_name),
+            &SqlText::new(schema_name),
+            &SqlText::new(procedure_name),
+            &SqlText::new(column_name),
+        )
+    }
+
+    /// Like [`Self::tables`], but yields typed [`TableInfo`] records rather than a raw cursor.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error};
+    ///
+    /// fn print_tables(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     for table in conn.table_infos("", "", "", "TABLE")? {
+    ///         println!("{}", table?.name);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn table_infos(
+        &self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+        table_type: &str,
+    ) -> Result<CatalogIter<CursorImpl<StatementImpl<'_>>, TableInfo>, Error> {
+        CatalogIter::new(self.tables(catalog_name, schema_name, table_name, table_type)?)
+    }
+
+    /// Like [`Self::columns`], but yields typed [`ColumnInfo`] records rather than a raw cursor.
+    pub fn column_infos(
+        &self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+        column_name: &str,
+    ) -> Result<CatalogIter<CursorImpl<StatementImpl<'_>>, ColumnInfo>, Error> {
+        CatalogIter::new(self.columns(catalog_name, schema_name, table_name, column_name)?)
+    }
+
+    /// Like [`Self::foreign_keys`], but yields typed [`ForeignKeyInfo`] records rather than a raw
+    /// cursor.
+    pub fn foreign_key_infos(
+        &self,
+        pk_catalog_name: &str,
+        pk_schema_name: &str,
+        pk_table_name: &str,
+        fk_catalog_name: &str,
+        fk_schema_name: &str,
+        fk_table_name: &str,
+    ) -> Result<CatalogIter<CursorImpl<StatementImpl<'_>>, ForeignKeyInfo>, Error> {
+        CatalogIter::new(self.foreign_keys(
+            pk_catalog_name,
+            pk_schema_name,
+            pk_table_name,
+            fk_catalog_name,
+            fk_schema_name,
+            fk_table_name,
+        )?)
+    }
+
+    /// Describes the shape of a table, i.e. its columns, primary key, foreign keys and indexes. The
+    /// description is aggregated from the results of [`Self::column_infos`],
+    /// [`Self::primary_keys`], [`Self::foreign_key_infos`] and [`Self::statistics`].
+    ///
+    /// Empty strings for `catalog_name` or `schema_name` do not restrict the search. Should the
+    /// table name be ambiguous, the first matching table reported by the driver is described.
+    /// `None` if no table with that name could be found.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error};
+    ///
+    /// /// `true` if the table exists in both data sources and has the same shape.
+    /// fn same_schema(a: &Connection<'_>, b: &Connection<'_>, table: &str) -> Result<bool, Error> {
+    ///     let a = a.describe_table("", "", table)?;
+    ///     let b = b.describe_table("", "", table)?;
+    ///     match (a, b) {
+    ///         (Some(a), Some(b)) => Ok(a.same_shape(&b)),
+    ///         _ => Ok(false),
+    ///     }
+    /// }
+    /// ```
+    pub fn describe_table(
+        &self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+    ) -> Result<Option<TableSchema>, Error> {
+        TableSchema::fetch(self, catalog_name, schema_name, table_name)
+    }
+
+    /// Information about the data types supported by the data source, as reported by
+    /// `SQLGetTypeInfo`. If `data_type` is `None` all data types are returned, otherwise only the
+    /// ones mapping to the specified SQL data type. The data types are ordered by `DATA_TYPE` and
+    /// then by how closely they map to the corresponding SQL data type.
+    ///
+    /// Intended to help applications to pick the concrete types offered by the data source, e.g.
+    /// when generating `CREATE TABLE` statements.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::{sys::SqlDataType, Connection, Error};
+    ///
+    /// /// Name of the type to use in DDL for 64 Bit integers, if the data source supports any.
+    /// fn big_int_type_name(conn: &Connection<'_>) -> Result<Option<String>, Error> {
+    ///     let type_infos = conn.type_info(Some(SqlDataType::EXT_BIG_INT))?;
+    ///     Ok(type_infos.into_iter().next().map(|info| info.type_name))
+    /// }
+    /// ```
+    pub fn type_info(&self, data_type: Option<SqlDataType>) -> Result<Vec<TypeInfo>, Error> {
+        let statement = self.allocate_statement()?;
+        TypeInfo::fetch(statement, data_type)
+    }
+
     /// The buffer descriptions for all standard buffers (not including extensions) returned in the
     /// columns query (e.g. [`Connection::columns`]).
     ///
@@ -697,6 +1181,17 @@ pub struct ConnectionOptions {
     pub login_timeout_sec: Option<u32>,
     /// Packet size in bytes. Not all drivers support this option.
     pub packet_size: Option<u32>,
+    /// Transaction isolation level of the connection. If `None` the default of the data source is
+    /// used.
+    ///
+    /// This corresponds to the `SQL_ATTR_TXN_ISOLATION` attribute in the ODBC specification.
+    pub isolation_level: Option<IsolationLevel>,
+    /// `Some(true)` opens the connection in read-only access mode. Drivers use this as a hint to
+    /// optimize transactions, and are not required to prevent statements from modifying the data
+    /// source. If `None` the default of the driver (usually read-write) is used.
+    ///
+    /// This corresponds to the `SQL_ATTR_ACCESS_MODE` attribute in the ODBC specification.
+    pub read_only: Option<bool>,
 }
 
 impl ConnectionOptions {
@@ -711,10 +1206,53 @@ impl ConnectionOptions {
         if let Some(packet_size) = self.packet_size {
             handle.set_packet_size(packet_size).into_result(handle)?;
         }
+        if let Some(isolation_level) = self.isolation_level {
+            handle
+                .set_transaction_isolation(isolation_level as u32)
+                .into_result(handle)?;
+        }
+        if let Some(read_only) = self.read_only {
+            handle.set_read_only(read_only).into_result(handle)?;
+        }
         Ok(())
     }
 }
 
+/// Transaction isolation level of a connection. See
+/// <https://learn.microsoft.com/en-us/sql/odbc/reference/develop-app/transaction-isolation-levels>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum IsolationLevel {
+    /// `SQL_TXN_READ_UNCOMMITTED`. Dirty reads, nonrepeatable reads and phantoms are possible.
+    ReadUncommitted = 1,
+    /// `SQL_TXN_READ_COMMITTED`. Dirty reads are not possible. Nonrepeatable reads and phantoms
+    /// are.
+    ReadCommitted = 2,
+    /// `SQL_TXN_REPEATABLE_READ`. Dirty reads and nonrepeatable reads are not possible. Phantoms
+    /// are.
+    RepeatableRead = 4,
+    /// `SQL_TXN_SERIALIZABLE`. Transactions are serializable. Dirty reads, nonrepeatable reads and
+    /// phantoms are not possible.
+    Serializable = 8,
+}
+
+impl IsolationLevel {
+    /// All isolation levels defined by the ODBC standard, ordered from least to most strict.
+    const ALL: [IsolationLevel; 4] = [
+        IsolationLevel::ReadUncommitted,
+        IsolationLevel::ReadCommitted,
+        IsolationLevel::RepeatableRead,
+        IsolationLevel::Serializable,
+    ];
+
+    /// Convert the `SQL_TXN_*` value reported by the driver into an isolation level. `None` if the
+    /// value does not correspond to any level defined by the ODBC standard (e.g. the snapshot
+    /// isolation of Microsoft SQL Server).
+    pub fn from_sql(value: u32) -> Option<Self> {
+        Self::ALL.into_iter().find(|level| *level as u32 == value)
+    }
+}
+
 /// You can use this method to escape a password so it is suitable to be appended to an ODBC
 /// connection string as the value for the `PWD` attribute. This method is only of interest for
 /// application in need to create their own connection strings.
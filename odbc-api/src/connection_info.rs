@@ -0,0 +1,357 @@
+use crate::{
+    handles::{self, slice_to_utf8, State},
+    Error,
+};
+
+// Numeric values of the `SQLGetInfo` information types. Not all of them are covered by
+// `odbc_sys::InfoType`.
+const SQL_DRIVER_NAME: u16 = 6;
+const SQL_DRIVER_VER: u16 = 7;
+const SQL_ODBC_VER: u16 = 10;
+const SQL_DBMS_NAME: u16 = 17;
+const SQL_DBMS_VER: u16 = 18;
+const SQL_IDENTIFIER_QUOTE_CHAR: u16 = 29;
+const SQL_SCHEMA_TERM: u16 = 39;
+const SQL_CATALOG_TERM: u16 = 42;
+const SQL_NUMERIC_FUNCTIONS: u16 = 49;
+const SQL_STRING_FUNCTIONS: u16 = 50;
+const SQL_SYSTEM_FUNCTIONS: u16 = 51;
+const SQL_TIMEDATE_FUNCTIONS: u16 = 52;
+const SQL_DRIVER_ODBC_VER: u16 = 77;
+const SQL_GETDATA_EXTENSIONS: u16 = 81;
+const SQL_MAX_STATEMENT_LEN: u16 = 105;
+const SQL_SQL_CONFORMANCE: u16 = 118;
+const SQL_BATCH_ROW_COUNT: u16 = 120;
+const SQL_BATCH_SUPPORT: u16 = 121;
+
+/// Capabilities of the driver and the data source, as reported by `SQLGetInfo`. Allows
+/// applications to adapt to the backend without parsing the name of the database management
+/// system. See [`crate::Connection::info`].
+///
+/// Information types every driver has to support are plain fields. The other ones are `None` if
+/// the driver does not support the information type.
+///
+/// See: <https://learn.microsoft.com/sql/odbc/reference/syntax/sqlgetinfo-function>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionInfo {
+    /// Name of the database management system. E.g. `Microsoft SQL Server`. `SQL_DBMS_NAME`.
+    pub dbms_name: String,
+    /// Version of the database management system. `SQL_DBMS_VER`.
+    pub dbms_version: String,
+    /// File name of the driver used to access the data source. `SQL_DRIVER_NAME`.
+    pub driver_name: String,
+    /// Version of the driver. `SQL_DRIVER_VER`.
+    pub driver_version: String,
+    /// Version of ODBC the driver supports, in the form `##.##`. `SQL_DRIVER_ODBC_VER`.
+    pub driver_odbc_version: String,
+    /// Version of ODBC the driver manager conforms to, in the form `##.##.0000`. `SQL_ODBC_VER`.
+    pub odbc_version: String,
+    /// Character used to delimit quoted identifiers, e.g. `"`. A single space if quoted
+    /// identifiers are not supported. `SQL_IDENTIFIER_QUOTE_CHAR`.
+    pub identifier_quote_char: Option<String>,
+    /// Name the data source uses for catalogs, e.g. `database`. Empty if catalogs are not
+    /// supported. `SQL_CATALOG_TERM`.
+    pub catalog_term: Option<String>,
+    /// Name the data source uses for schemas, e.g. `owner`. Empty if schemas are not supported.
+    /// `SQL_SCHEMA_TERM`.
+    pub schema_term: Option<String>,
+    /// Level of SQL-92 supported by the driver. Also `None` if the driver reports a value not
+    /// defined by the ODBC standard. `SQL_SQL_CONFORMANCE`.
+    pub sql_conformance: Option<SqlConformance>,
+    /// Scalar functions supported by the driver and the data source.
+    pub scalar_functions: Option<ScalarFunctions>,
+    /// Maximum length of an SQL statement in characters. `Some(0)` if there is no maximum length
+    /// or it is unknown. `SQL_MAX_STATEMENT_LEN`.
+    pub max_statement_len: Option<u32>,
+    /// Extensions to the usage of `SQLGetData` supported by the driver. `SQL_GETDATA_EXTENSIONS`.
+    pub get_data_extensions: Option<GetDataExtensions>,
+    /// Support for executing batches of SQL statements. `SQL_BATCH_SUPPORT` and
+    /// `SQL_BATCH_ROW_COUNT`.
+    pub batch_support: Option<BatchSupport>,
+}
+
+impl ConnectionInfo {
+    pub(crate) fn fetch(connection: &handles::Connection<'_>) -> Result<Self, Error> {
+        let mut buf = Vec::new();
+        let mut string = |info_type: u16| -> Result<String, Error> {
+            connection
+                .fetch_info_string(info_type, &mut buf)
+                .into_result(connection)?;
+            Ok(slice_to_utf8(&buf).unwrap())
+        };
+        let dbms_name = string(SQL_DBMS_NAME)?;
+        let dbms_version = string(SQL_DBMS_VER)?;
+        let driver_name = string(SQL_DRIVER_NAME)?;
+        let driver_version = string(SQL_DRIVER_VER)?;
+        let driver_odbc_version = string(SQL_DRIVER_ODBC_VER)?;
+        let odbc_version = string(SQL_ODBC_VER)?;
+        let identifier_quote_char = optional(string(SQL_IDENTIFIER_QUOTE_CHAR))?;
+        let catalog_term = optional(string(SQL_CATALOG_TERM))?;
+        let schema_term = optional(string(SQL_SCHEMA_TERM))?;
+
+        let numeric =
+            |info_type: u16| optional(connection.numeric_info(info_type).into_result(connection));
+        let scalar_functions = match (
+            numeric(SQL_NUMERIC_FUNCTIONS)?,
+            numeric(SQL_STRING_FUNCTIONS)?,
+            numeric(SQL_SYSTEM_FUNCTIONS)?,
+            numeric(SQL_TIMEDATE_FUNCTIONS)?,
+        ) {
+            (Some(numeric), Some(string), Some(system), Some(time_date)) => Some(ScalarFunctions {
+                numeric,
+                string,
+                system,
+                time_date,
+            }),
+            _ => None,
+        };
+        let batch_support = match (numeric(SQL_BATCH_SUPPORT)?, numeric(SQL_BATCH_ROW_COUNT)?) {
+            (Some(batch_support), Some(batch_row_count)) => {
+                Some(BatchSupport::from_bitmasks(batch_support, batch_row_count))
+            }
+            _ => None,
+        };
+        Ok(Self {
+            dbms_name,
+            dbms_version,
+            driver_name,
+            driver_version,
+            driver_odbc_version,
+            odbc_version,
+            identifier_quote_char,
+            catalog_term,
+            schema_term,
+            sql_conformance: numeric(SQL_SQL_CONFORMANCE)?.and_then(SqlConformance::from_sql),
+            scalar_functions,
+            max_statement_len: numeric(SQL_MAX_STATEMENT_LEN)?,
+            get_data_extensions: numeric(SQL_GETDATA_EXTENSIONS)?
+                .map(GetDataExtensions::from_bitmask),
+            batch_support,
+        })
+    }
+
+    /// `true` if the data source supports quoted identifiers.
+    pub fn supports_quoted_identifiers(&self) -> bool {
+        self.identifier_quote_char
+            .as_deref()
+            .is_some_and(|quote_char| !quote_char.trim().is_empty())
+    }
+}
+
+/// Maps the errors a driver reports for information types it does not support to `None`.
+fn optional<T>(result: Result<T, Error>) -> Result<Option<T>, Error> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(Error::Diagnostics { record, .. })
+            if record.state == State::INVALID_INFO_TYPE
+                || record.state == State::OPTIONAL_FEATURE_NOT_IMPLEMENTED =>
+        {
+            Ok(None)
+        }
+        Err(other) => Err(other),
+    }
+}
+
+/// Level of SQL-92 conformance of the driver. `SQL_SQL_CONFORMANCE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SqlConformance {
+    /// `SQL_SC_SQL92_ENTRY`. Entry level SQL-92 compliant.
+    Sql92Entry,
+    /// `SQL_SC_FIPS127_2_TRANSITIONAL`. FIPS 127-2 transitional level compliant.
+    Fips127_2Transitional,
+    /// `SQL_SC_SQL92_INTERMEDIATE`. Intermediate level SQL-92 compliant.
+    Sql92Intermediate,
+    /// `SQL_SC_SQL92_FULL`. Full level SQL-92 compliant.
+    Sql92Full,
+}
+
+impl SqlConformance {
+    fn from_sql(value: u32) -> Option<Self> {
+        match value {
+            1 => Some(SqlConformance::Sql92Entry),
+            2 => Some(SqlConformance::Fips127_2Transitional),
+            4 => Some(SqlConformance::Sql92Intermediate),
+            8 => Some(SqlConformance::Sql92Full),
+            _ => None,
+        }
+    }
+}
+
+/// Bitmasks of the scalar functions supported by the driver and the data source. The individual
+/// bits are defined by the ODBC standard, e.g. `SQL_FN_STR_CONCAT` (`0x1`) for the string
+/// functions. See:
+/// <https://learn.microsoft.com/sql/odbc/reference/appendixes/scalar-function-escape-sequence>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScalarFunctions {
+    /// `SQL_NUMERIC_FUNCTIONS`. Bitmask of `SQL_FN_NUM_*` values.
+    pub numeric: u32,
+    /// `SQL_STRING_FUNCTIONS`. Bitmask of `SQL_FN_STR_*` values.
+    pub string: u32,
+    /// `SQL_SYSTEM_FUNCTIONS`. Bitmask of `SQL_FN_SYS_*` values.
+    pub system: u32,
+    /// `SQL_TIMEDATE_FUNCTIONS`. Bitmask of `SQL_FN_TD_*` values.
+    pub time_date: u32,
+}
+
+/// Extensions to `SQLGetData` supported by the driver. `SQL_GETDATA_EXTENSIONS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetDataExtensions {
+    /// `SQL_GD_ANY_COLUMN`. `SQLGetData` can be called for any unbound column, including those
+    /// before the last bound column.
+    pub any_column: bool,
+    /// `SQL_GD_ANY_ORDER`. `SQLGetData` can be called for unbound columns in any order.
+    pub any_order: bool,
+    /// `SQL_GD_BLOCK`. `SQLGetData` can be called for an unbound column in any row in a block of
+    /// data (where the rowset size is greater than 1).
+    pub block: bool,
+    /// `SQL_GD_BOUND`. `SQLGetData` can be called for bound columns in addition to unbound
+    /// columns.
+    pub bound: bool,
+    /// `SQL_GD_OUTPUT_PARAMS`. `SQLGetData` can be called to return output parameter values.
+    pub output_params: bool,
+}
+
+impl GetDataExtensions {
+    fn from_bitmask(mask: u32) -> Self {
+        Self {
+            any_column: mask & 0x1 != 0,
+            any_order: mask & 0x2 != 0,
+            block: mask & 0x4 != 0,
+            bound: mask & 0x8 != 0,
+            output_params: mask & 0x10 != 0,
+        }
+    }
+}
+
+/// Support of the driver for executing batches of SQL statements. `SQL_BATCH_SUPPORT` and
+/// `SQL_BATCH_ROW_COUNT`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchSupport {
+    /// `SQL_BS_SELECT_EXPLICIT`. Explicit batches with result set generating statements are
+    /// supported.
+    pub select_explicit: bool,
+    /// `SQL_BS_ROW_COUNT_EXPLICIT`. Explicit batches with row count generating statements are
+    /// supported.
+    pub row_count_explicit: bool,
+    /// `SQL_BS_SELECT_PROC`. Explicit procedures with result set generating statements are
+    /// supported.
+    pub select_procedure: bool,
+    /// `SQL_BS_ROW_COUNT_PROC`. Explicit procedures with row count generating statements are
+    /// supported.
+    pub row_count_procedure: bool,
+    /// `SQL_BRC_ROLLED_UP`. Row counts of consecutive `INSERT`, `DELETE` or `UPDATE` statements
+    /// are rolled up into one.
+    pub row_count_rolled_up: bool,
+    /// `SQL_BRC_PROCEDURES`. Row counts are available when executing a procedure.
+    pub row_count_procedures: bool,
+    /// `SQL_BRC_EXPLICIT`. Row counts are available when executing an explicit batch.
+    pub row_count_explicit_batch: bool,
+}
+
+impl BatchSupport {
+    fn from_bitmasks(batch_support: u32, batch_row_count: u32) -> Self {
+        Self {
+            select_explicit: batch_support & 0x1 != 0,
+            row_count_explicit: batch_support & 0x2 != 0,
+            select_procedure: batch_support & 0x4 != 0,
+            row_count_procedure: batch_support & 0x8 != 0,
+            row_count_procedures: batch_row_count & 0x1 != 0,
+            row_count_explicit_batch: batch_row_count & 0x2 != 0,
+            row_count_rolled_up: batch_row_count & 0x4 != 0,
+        }
+    }
+
+    /// `true` if the driver supports any kind of batches.
+    pub fn any(&self) -> bool {
+        self.select_explicit
+            || self.row_count_explicit
+            || self.select_procedure
+            || self.row_count_procedure
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+        handles::{Record, State},
+        Error,
+    };
+
+    use super::{optional, BatchSupport, SqlConformance};
+
+    #[test]
+    fn batch_support_from_bitmasks() {
+        // SQL_BS_SELECT_EXPLICIT | SQL_BS_ROW_COUNT_PROC and SQL_BRC_EXPLICIT
+        let support = BatchSupport::from_bitmasks(0x1 | 0x8, 0x2);
+
+        assert_eq!(
+            BatchSupport {
+                select_explicit: true,
+                row_count_explicit: false,
+                select_procedure: false,
+                row_count_procedure: true,
+                row_count_rolled_up: false,
+                row_count_procedures: false,
+                row_count_explicit_batch: true,
+            },
+            support
+        );
+    }
+
+    #[test]
+    fn batch_row_count_bits() {
+        let procedures = BatchSupport::from_bitmasks(0, 0x1);
+        let explicit = BatchSupport::from_bitmasks(0, 0x2);
+        let rolled_up = BatchSupport::from_bitmasks(0, 0x4);
+
+        assert!(procedures.row_count_procedures);
+        assert!(!procedures.row_count_explicit_batch && !procedures.row_count_rolled_up);
+        assert!(explicit.row_count_explicit_batch);
+        assert!(!explicit.row_count_procedures && !explicit.row_count_rolled_up);
+        assert!(rolled_up.row_count_rolled_up);
+        assert!(!rolled_up.row_count_procedures && !rolled_up.row_count_explicit_batch);
+    }
+
+    #[test]
+    fn sql_conformance_values() {
+        assert_eq!(
+            Some(SqlConformance::Sql92Entry),
+            SqlConformance::from_sql(0x1)
+        );
+        assert_eq!(
+            Some(SqlConformance::Fips127_2Transitional),
+            SqlConformance::from_sql(0x2)
+        );
+        assert_eq!(
+            Some(SqlConformance::Sql92Intermediate),
+            SqlConformance::from_sql(0x4)
+        );
+        assert_eq!(
+            Some(SqlConformance::Sql92Full),
+            SqlConformance::from_sql(0x8)
+        );
+        assert_eq!(None, SqlConformance::from_sql(0x10));
+    }
+
+    #[test]
+    fn unsupported_info_types_are_none() {
+        let diagnostics = |state| Error::Diagnostics {
+            record: Record {
+                state,
+                ..Record::default()
+            },
+            function: "SQLGetInfo",
+        };
+
+        let invalid_info_type = optional::<u32>(Err(diagnostics(State::INVALID_INFO_TYPE)));
+        let not_implemented =
+            optional::<u32>(Err(diagnostics(State::OPTIONAL_FEATURE_NOT_IMPLEMENTED)));
+        let other = optional::<u32>(Err(diagnostics(State::INVALID_ATTRIBUTE_VALUE)));
+
+        assert!(matches!(invalid_info_type, Ok(None)));
+        assert!(matches!(not_implemented, Ok(None)));
+        assert!(matches!(other, Err(Error::Diagnostics { .. })));
+        assert!(matches!(optional(Ok(42)), Ok(Some(42))));
+    }
+}
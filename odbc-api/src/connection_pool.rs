@@ -0,0 +1,390 @@
+use std::{
+    collections::VecDeque,
+    fmt::{self, Debug},
+    mem::forget,
+    ops::Deref,
+    sync::{Condvar, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use log::debug;
+
+use crate::{environment, Connection, ConnectionOptions, Environment, Error};
+
+/// Options governing the size and eviction behaviour of a [`ConnectionPool`].
+#[derive(Clone, Copy, Debug)]
+pub struct PoolOptions {
+    /// Maximum number of connections the pool is going to open at the same time. This includes
+    /// connections currently checked out, as well as idle ones. Once this limit is reached,
+    /// [`ConnectionPool::get`] blocks until a connection is returned to the pool. Must not be `0`.
+    pub max_size: usize,
+    /// Number of connections the pool opens eagerly on construction. Idle connections are not
+    /// evicted, if this would cause the number of open connections to drop below this number. Dead
+    /// connections discarded during checkout are replaced, until this many are open again.
+    pub min_size: usize,
+    /// Idle connections which have not been checked out for longer than this are closed. `None`
+    /// means idle connections are kept open indefinitely.
+    pub idle_timeout: Option<Duration>,
+    /// Maximum time [`ConnectionPool::get`] waits for a connection to become available, if the
+    /// pool is exhausted. `None` means waiting indefinitely.
+    pub checkout_timeout: Option<Duration>,
+}
+
+impl Default for PoolOptions {
+    fn default() -> Self {
+        Self {
+            max_size: 10,
+            min_size: 0,
+            idle_timeout: Some(Duration::from_secs(600)),
+            checkout_timeout: Some(Duration::from_secs(30)),
+        }
+    }
+}
+
+/// A thread safe pool of open connections to the same data source.
+///
+/// In contrast to the connection pooling offered by the ODBC driver manager (see
+/// [`crate::Environment::set_connection_pooling`]), this pool is local to the instance, does not
+/// change process global state and gives the application control over its size and the time idle
+/// connections are kept around.
+///
+/// Connections are validated using [`Connection::is_dead`] before they are handed out. Once a
+/// [`PooledConnection`] is dropped any open transaction is rolled back and autocommit mode is
+/// enabled again, before the connection is returned to the pool.
+///
+/// # Example
+///
+/// ```no_run
+/// use odbc_api::{ConnectionPool, ConnectionOptions, PoolOptions};
+///
+/// let pool = ConnectionPool::new(
+///     "Driver={ODBC Driver 18 for SQL Server};Server=localhost;UID=SA;PWD=My@Test@Password1;",
+///     ConnectionOptions::default(),
+///     PoolOptions { max_size: 4, ..PoolOptions::default() },
+/// )?;
+///
+/// // `ConnectionPool` is `Sync`, so we can share it between threads.
+/// std::thread::scope(|s| {
+///     for _ in 0..8 {
+///         s.spawn(|| {
+///             let conn = pool.get().unwrap();
+///             conn.execute("SELECT 42", ()).unwrap();
+///             // Connection is returned to the pool once `conn` goes out of scope.
+///         });
+///     }
+/// });
+/// # Ok::<(), odbc_api::Error>(())
+/// ```
+pub struct ConnectionPool {
+    environment: &'static Environment,
+    connection_string: String,
+    connection_options: ConnectionOptions,
+    options: PoolOptions,
+    state: Mutex<PoolState>,
+    /// Notified every time a connection is returned to the pool, or a slot for a new connection is
+    /// freed.
+    available: Condvar,
+}
+
+struct PoolState {
+    /// Connections not currently checked out. Most recently returned connections are at the back.
+    idle: VecDeque<IdleConnection>,
+    /// Number of connections currently open. Includes idle and checked out connections, as well as
+    /// connections which are currently in the process of being established.
+    num_open: usize,
+}
+
+struct IdleConnection {
+    connection: Connection<'static>,
+    since: Instant,
+}
+
+impl ConnectionPool {
+    /// Creates a new connection pool using the static environment returned by
+    /// [`crate::environment`]. `min_size` connections are opened eagerly.
+    ///
+    /// # Parameters
+    ///
+    /// * `connection_string`: Used to open every connection in the pool. See
+    ///   [`crate::Environment::connect_with_connection_string`].
+    /// * `connection_options`: Applied to every connection before it is opened.
+    /// * `options`: Governs size and eviction behaviour of the pool.
+    pub fn new(
+        connection_string: &str,
+        connection_options: ConnectionOptions,
+        options: PoolOptions,
+    ) -> Result<Self, Error> {
+        Self::with_environment(
+            environment()?,
+            connection_string,
+            connection_options,
+            options,
+        )
+    }
+
+    /// Like [`Self::new`], but uses the environment provided by the application rather than the
+    /// one returned by [`crate::environment`].
+    pub fn with_environment(
+        environment: &'static Environment,
+        connection_string: &str,
+        connection_options: ConnectionOptions,
+        options: PoolOptions,
+    ) -> Result<Self, Error> {
+        assert!(options.max_size > 0, "Maximum pool size must not be zero.");
+        assert!(
+            options.min_size <= options.max_size,
+            "Minimum pool size must not exceed the maximum pool size."
+        );
+        let pool = Self {
+            environment,
+            connection_string: connection_string.to_owned(),
+            connection_options,
+            options,
+            state: Mutex::new(PoolState {
+                idle: VecDeque::with_capacity(options.max_size),
+                num_open: 0,
+            }),
+            available: Condvar::new(),
+        };
+        let mut state = pool.lock_state();
+        for _ in 0..options.min_size {
+            let connection = pool.connect()?;
+            state.num_open += 1;
+            state.idle.push_back(IdleConnection {
+                connection,
+                since: Instant::now(),
+            });
+        }
+        drop(state);
+        Ok(pool)
+    }
+
+    /// Check out a connection from the pool. Idle connections are reused if they are still alive.
+    /// If no idle connection is available a new one is opened, as long as this does not exceed
+    /// [`PoolOptions::max_size`]. Otherwise this method blocks until another thread returns a
+    /// connection to the pool, or [`PoolOptions::checkout_timeout`] elapsed, in which case
+    /// [`Error::ConnectionPoolTimeout`] is returned.
+    pub fn get(&self) -> Result<PooledConnection<'_>, Error> {
+        let deadline = self
+            .options
+            .checkout_timeout
+            .map(|timeout| Instant::now() + timeout);
+        let mut state = self.lock_state();
+        loop {
+            self.evict_idle(&mut state);
+            if let Some(IdleConnection { connection, .. }) = state.idle.pop_back() {
+                // Do not hold the lock, while talking to the data source.
+                drop(state);
+                if matches!(connection.is_dead(), Ok(false)) {
+                    return Ok(PooledConnection::new(connection, self));
+                }
+                debug!("Discarding dead connection from connection pool.");
+                discard(connection);
+                self.release_slot();
+                self.replenish();
+                state = self.lock_state();
+                continue;
+            }
+            if state.num_open < self.options.max_size {
+                // Reserve a slot for the new connection, before releasing the lock.
+                state.num_open += 1;
+                drop(state);
+                return match self.connect() {
+                    Ok(connection) => Ok(PooledConnection::new(connection, self)),
+                    Err(error) => {
+                        self.release_slot();
+                        Err(error)
+                    }
+                };
+            }
+            // Pool is exhausted. Wait for a connection to be returned.
+            state = match deadline {
+                None => self
+                    .available
+                    .wait(state)
+                    .unwrap_or_else(PoisonError::into_inner),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(Error::ConnectionPoolTimeout {
+                            timeout: self.options.checkout_timeout.unwrap(),
+                        });
+                    }
+                    self.available
+                        .wait_timeout(state, deadline - now)
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .0
+                }
+            };
+        }
+    }
+
+    /// Number of open connections. Includes idle connections as well as connections currently
+    /// checked out.
+    pub fn num_open(&self) -> usize {
+        self.lock_state().num_open
+    }
+
+    /// Number of connections currently idle in the pool.
+    pub fn num_idle(&self) -> usize {
+        self.lock_state().idle.len()
+    }
+
+    /// Locks the state of the pool. The state is consistent whenever the lock is released, so a
+    /// panic of another thread holding the lock does not render the pool unusable.
+    fn lock_state(&self) -> MutexGuard<'_, PoolState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn connect(&self) -> Result<Connection<'static>, Error> {
+        self.environment
+            .connect_with_connection_string(&self.connection_string, self.connection_options)
+    }
+
+    /// Close idle connections which exceeded the idle timeout, as long as this would not drop the
+    /// number of open connections below the minimum size of the pool.
+    fn evict_idle(&self, state: &mut MutexGuard<'_, PoolState>) {
+        let Some(idle_timeout) = self.options.idle_timeout else {
+            return;
+        };
+        // Least recently used connections are at the front
+        while state.num_open > self.options.min_size
+            && state
+                .idle
+                .front()
+                .is_some_and(|idle| idle.since.elapsed() > idle_timeout)
+        {
+            let idle = state.idle.pop_front().unwrap();
+            discard(idle.connection);
+            state.num_open -= 1;
+        }
+    }
+
+    /// Return a connection to the pool. Resets transaction state and discards the connection if
+    /// this fails.
+    fn check_in(&self, connection: Connection<'static>) {
+        // Roll back any open transaction, before switching (back) to autocommit mode. Otherwise
+        // enabling autocommit would commit it.
+        let is_reusable = connection.rollback().is_ok()
+            && connection.set_autocommit(true).is_ok()
+            && matches!(connection.is_dead(), Ok(false));
+        if !is_reusable {
+            debug!(
+                "Discarding connection returned to the pool, since its state could not be reset."
+            );
+            discard(connection);
+            self.release_slot();
+            return;
+        }
+        self.push_idle(connection);
+    }
+
+    /// Opens new idle connections until [`PoolOptions::min_size`] connections are open again, e.g.
+    /// after dead connections have been discarded. Stops at the first connection which can not be
+    /// opened, since the caller does not depend on these connections.
+    fn replenish(&self) {
+        loop {
+            let mut state = self.lock_state();
+            if state.num_open >= self.options.min_size {
+                return;
+            }
+            // Reserve a slot for the new connection, before releasing the lock.
+            state.num_open += 1;
+            drop(state);
+            match self.connect() {
+                Ok(connection) => self.push_idle(connection),
+                Err(error) => {
+                    debug!("Failed to replenish connection pool: {error}");
+                    self.release_slot();
+                    return;
+                }
+            }
+        }
+    }
+
+    fn push_idle(&self, connection: Connection<'static>) {
+        let mut state = self.lock_state();
+        state.idle.push_back(IdleConnection {
+            connection,
+            since: Instant::now(),
+        });
+        drop(state);
+        self.available.notify_one();
+    }
+
+    fn release_slot(&self) {
+        self.lock_state().num_open -= 1;
+        self.available.notify_one();
+    }
+}
+
+impl Debug for ConnectionPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.lock_state();
+        f.debug_struct("ConnectionPool")
+            .field("options", &self.options)
+            .field("num_open", &state.num_open)
+            .field("num_idle", &state.idle.len())
+            .finish()
+    }
+}
+
+/// Closes a connection which is not fit to be reused. In contrast to just dropping it, this does not
+/// panic if disconnecting fails, which is not unlikely for connections which died. In that case we
+/// rather leak the handle.
+fn discard(connection: Connection<'static>) {
+    let mut handle = connection.into_handle();
+    if handle.disconnect().into_result(&handle).is_ok() {
+        drop(handle)
+    } else {
+        debug!("Failed to disconnect discarded connection. Leaking connection handle.");
+        forget(handle)
+    }
+}
+
+/// A connection checked out from a [`ConnectionPool`]. Dereferences to [`Connection`]. The
+/// connection is returned to the pool once this guard is dropped.
+pub struct PooledConnection<'p> {
+    /// Always `Some`, until the connection is returned to the pool or detached from it.
+    connection: Option<Connection<'static>>,
+    pool: &'p ConnectionPool,
+}
+
+impl<'p> PooledConnection<'p> {
+    fn new(connection: Connection<'static>, pool: &'p ConnectionPool) -> Self {
+        Self {
+            connection: Some(connection),
+            pool,
+        }
+    }
+
+    /// Take ownership of the connection and remove it from the pool. This frees up a slot in the
+    /// pool for a new connection.
+    pub fn detach(mut self) -> Connection<'static> {
+        let connection = self.connection.take().unwrap();
+        self.pool.release_slot();
+        connection
+    }
+}
+
+impl Deref for PooledConnection<'_> {
+    type Target = Connection<'static>;
+
+    fn deref(&self) -> &Self::Target {
+        self.connection.as_ref().unwrap()
+    }
+}
+
+impl Drop for PooledConnection<'_> {
+    fn drop(&mut self) {
+        if let Some(connection) = self.connection.take() {
+            self.pool.check_in(connection);
+        }
+    }
+}
+
+impl Debug for PooledConnection<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PooledConnection")
+    }
+}
@@ -1,15 +1,20 @@
-use odbc_sys::HStmt;
+use odbc_sys::{BulkOperation, FetchOrientation, HStmt, Lock, Operation};
 
 use crate::{
-    buffers::Indicator,
+    buffers::{AnySliceMut, ColumnarAnyBuffer, Indicator},
+    columnar_bulk_inserter::BoundAt,
     error::ExtendResult,
     handles::{AsStatementRef, CDataMut, SqlResult, State, Statement, StatementRef},
     parameter::{Binary, CElement, Text, VarCell, VarKind, WideText},
     sleep::{wait_for, Sleep},
-    Error, ResultSetMetadata,
+    CancelHandle, Error, FromRow, FromRowCursor, FromSql, ResultSetMetadata,
 };
 
+#[cfg(feature = "futures")]
+use crate::sleep::{cancel_and_wait, cancel_statement};
+
 use std::{
+    marker::PhantomData,
     mem::{size_of, MaybeUninit},
     ptr,
     thread::panicking,
@@ -72,6 +77,17 @@ pub trait Cursor: ResultSetMetadata {
         Ok(ret)
     }
 
+    /// Fetches the rows of this cursor one by one as values of type `R`, mapping the fields of `R`
+    /// to the columns of the result set by name. Like [`Self::next_row`] this is **Slow**, but
+    /// convenient. See [`FromRow`].
+    fn rows_as<R>(self) -> FromRowCursor<Self, R>
+    where
+        Self: Sized,
+        R: FromRow,
+    {
+        FromRowCursor::new(self)
+    }
+
     /// Binds this cursor to a buffer holding a row set.
     fn bind_buffer<B>(self, row_set_buffer: B) -> Result<BlockCursor<Self, B>, Error>
     where
@@ -111,16 +127,41 @@ impl<'s> CursorRow<'s> {
         col_or_param_num: u16,
         target: &mut (impl CElement + CDataMut),
     ) -> Result<(), Error> {
-        self.statement
-            .get_data(col_or_param_num, target)
-            .into_result(&self.statement)
-            .provide_context_for_diagnostic(|record, function| {
-                if record.state == State::INDICATOR_VARIABLE_REQUIRED_BUT_NOT_SUPPLIED {
-                    Error::UnableToRepresentNull(record)
-                } else {
-                    Error::Diagnostics { record, function }
-                }
-            })
+        let result = self.statement.get_data(col_or_param_num, target);
+        get_data_result(result, &self.statement)
+    }
+
+    /// Reads a field from the current row of the result set as a value of type `T`. Column index
+    /// starts at `1`. Variadic values like text or binary data are read completely, however large
+    /// they are. Each field should only be read once. See [`FromSql`].
+    ///
+    /// # Return
+    ///
+    /// [`Error::UnexpectedNull`] if the field is `NULL`, but `T` can not represent `NULL`. Use
+    /// `Option<T>` for nullable columns. [`Error::IncompatibleColumnType`] if the driver can not
+    /// convert the value of the field into `T`.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Cursor, Error};
+    ///
+    /// fn first_name_and_age(mut cursor: impl Cursor) -> Result<Option<(String, Option<i32>)>, Error> {
+    ///     if let Some(mut row) = cursor.next_row()? {
+    ///         let name = row.get(1)?;
+    ///         let age = row.get(2)?;
+    ///         Ok(Some((name, age)))
+    ///     } else {
+    ///         Ok(None)
+    ///     }
+    /// }
+    /// ```
+    pub fn get<T>(&mut self, col_or_param_num: u16) -> Result<T, Error>
+    where
+        T: FromSql,
+    {
+        match T::from_sql(self, col_or_param_num)? {
+            Some(value) => Ok(value),
+            None => T::from_null(col_or_param_num),
+        }
     }
 
     /// Retrieves arbitrary large character data from the row and stores it in the buffer. Column
@@ -189,6 +230,33 @@ impl<'s> CursorRow<'s> {
         col_or_param_num: u16,
         buf: &mut Vec<K::Element>,
     ) -> Result<bool, Error> {
+        let mut retrieval = VarDataRetrieval::<K>::new(buf);
+        loop {
+            let mut target = retrieval.target(buf);
+            self.get_data(col_or_param_num, &mut target)?;
+            let part = FetchedPart::of(&target);
+            if let Some(not_null) = retrieval.process(part, buf) {
+                return Ok(not_null);
+            }
+        }
+    }
+}
+
+/// Retrieves an arbitrary large variadic value using repeated calls to `SQLGetData`. Keeps track of
+/// the part of the buffer the **next** part of the value is going to be written into, whereas the
+/// buffer contains the entire accumulated value so far. Shared between [`CursorRow`] and
+/// [`CursorRowPolling`].
+struct VarDataRetrieval<K> {
+    /// Start of the window in the buffer, which is going to hold the next part of the value.
+    offset: usize,
+    /// Did we learn how much capacity we need in the last iteration? We use this only to panic on
+    /// erroneous implementations of get_data and avoid endless looping until we run out of memory.
+    remaining_length_known: bool,
+    kind: PhantomData<K>,
+}
+
+impl<K: VarKind> VarDataRetrieval<K> {
+    fn new(buf: &mut Vec<K::Element>) -> Self {
         if buf.capacity() == 0 {
             // User did just provide an empty buffer. So it is fair to assume not much domain
             // knowledge has been used to decide its size. We just default to 256 to increase the
@@ -201,85 +269,113 @@ impl<'s> CursorRow<'s> {
         }
         // Utilize all of the allocated buffer.
         buf.resize(buf.capacity(), K::ZERO);
+        Self {
+            offset: 0,
+            remaining_length_known: false,
+            kind: PhantomData,
+        }
+    }
 
-        // Did we learn how much capacity we need in the last iteration? We use this only to panic
-        // on erroneous implementations of get_data and avoid endless looping until we run out of
-        // memory.
-        let mut remaining_length_known = false;
-        // We repeatedly fetch data and add it to the buffer. The buffer length is therefore the
-        // accumulated value size. The target always points to the last window in buf which is going
-        // to contain the **next** part of the data, whereas buf contains the entire accumulated
-        // value so far.
-        let mut target =
-            VarCell::<&mut [K::Element], K>::from_buffer(buf.as_mut_slice(), Indicator::NoTotal);
-        self.get_data(col_or_param_num, &mut target)?;
-        while !target.is_complete() {
-            // Amount of payload bytes (excluding terminating zeros) fetched with the last call to
+    /// Target for the next call to `get_data`.
+    fn target<'b>(&self, buf: &'b mut [K::Element]) -> VarCell<&'b mut [K::Element], K> {
+        VarCell::from_buffer(&mut buf[self.offset..], Indicator::NoTotal)
+    }
+
+    /// Processes the part of the value fetched with the last call to `get_data`. Returns `None` if
+    /// the value is not complete yet and `get_data` must be called again with a new target. If the
+    /// value is complete, `true` indicates that it is not `NULL`.
+    fn process(&mut self, part: FetchedPart, buf: &mut Vec<K::Element>) -> Option<bool> {
+        if part.is_complete {
+            // We did get the complete value, including the terminating zero. Let's resize the
+            // buffer to match the retrieved value exactly (excluding terminating zero).
+            return if let Some(len_in_bytes) = part.indicator.length() {
+                // Since the indicator refers to value length without terminating zero, and
+                // capacity is including the terminating zero this also implicitly drops the
+                // terminating zero at the end of the buffer.
+                let shrink_by_bytes = part.capacity_in_bytes - len_in_bytes;
+                let shrink_by_chars = shrink_by_bytes / size_of::<K::Element>();
+                buf.resize(buf.len() - shrink_by_chars, K::ZERO);
+                Some(true)
+            } else {
+                // value is NULL
+                buf.clear();
+                Some(false)
+            };
+        }
+        // Amount of payload bytes (excluding terminating zeros) fetched with the last call to
+        // get_data.
+        let fetched = part
+            .len_in_bytes
+            .expect("ODBC driver must always report how many bytes were fetched.");
+        match part.indicator {
+            // If Null the value would be complete
+            Indicator::Null => unreachable!(),
+            // We do not know how large the value is. Let's fetch the data with repeated calls to
             // get_data.
-            let fetched = target
-                .len_in_bytes()
-                .expect("ODBC driver must always report how many bytes were fetched.");
-            match target.indicator() {
-                // If Null the value would be complete
-                Indicator::Null => unreachable!(),
-                // We do not know how large the value is. Let's fetch the data with repeated calls
-                // to get_data.
-                Indicator::NoTotal => {
-                    let old_len = buf.len();
-                    // Use an exponential strategy for increasing buffer size.
-                    buf.resize(old_len * 2, K::ZERO);
-                    let buf_extend = &mut buf[(old_len - K::TERMINATING_ZEROES)..];
-                    target = VarCell::<&mut [K::Element], K>::from_buffer(
-                        buf_extend,
-                        Indicator::NoTotal,
-                    );
-                }
-                // We did not get all of the value in one go, but the data source has been friendly
-                // enough to tell us how much is missing.
-                Indicator::Length(len) => {
-                    if remaining_length_known {
-                        panic!(
-                            "SQLGetData has been unable to fetch all data, even though the \
-                            capacity of the target buffer has been adapted to hold the entire \
-                            payload based on the indicator of the last part. You may consider \
-                            filing a bug with the ODBC driver you are using."
-                        )
-                    }
-                    remaining_length_known = true;
-                    // Amount of bytes missing from the value using get_data, excluding terminating
-                    // zero.
-                    let still_missing_in_bytes = len - fetched;
-                    let still_missing = still_missing_in_bytes / size_of::<K::Element>();
-                    let old_len = buf.len();
-                    buf.resize(old_len + still_missing, K::ZERO);
-                    let buf_extend = &mut buf[(old_len - K::TERMINATING_ZEROES)..];
-                    target = VarCell::<&mut [K::Element], K>::from_buffer(
-                        buf_extend,
-                        Indicator::NoTotal,
-                    );
+            Indicator::NoTotal => {
+                let old_len = buf.len();
+                // Use an exponential strategy for increasing buffer size.
+                buf.resize(old_len * 2, K::ZERO);
+                self.offset = old_len - K::TERMINATING_ZEROES;
+            }
+            // We did not get all of the value in one go, but the data source has been friendly
+            // enough to tell us how much is missing.
+            Indicator::Length(len) => {
+                if self.remaining_length_known {
+                    panic!(
+                        "SQLGetData has been unable to fetch all data, even though the capacity \
+                        of the target buffer has been adapted to hold the entire payload based on \
+                        the indicator of the last part. You may consider filing a bug with the \
+                        ODBC driver you are using."
+                    )
                 }
+                self.remaining_length_known = true;
+                // Amount of bytes missing from the value using get_data, excluding terminating
+                // zero.
+                let still_missing_in_bytes = len - fetched;
+                let still_missing = still_missing_in_bytes / size_of::<K::Element>();
+                let old_len = buf.len();
+                buf.resize(old_len + still_missing, K::ZERO);
+                self.offset = old_len - K::TERMINATING_ZEROES;
             }
-            // Fetch binary data into buffer.
-            self.get_data(col_or_param_num, &mut target)?;
         }
-        // We did get the complete value, including the terminating zero. Let's resize the buffer to
-        // match the retrieved value exactly (excluding terminating zero).
-        if let Some(len_in_bytes) = target.indicator().length() {
-            // Since the indicator refers to value length without terminating zero, and capacity is
-            // including the terminating zero this also implicitly drops the terminating zero at the
-            // end of the buffer.
-            let shrink_by_bytes = target.capacity_in_bytes() - len_in_bytes;
-            let shrink_by_chars = shrink_by_bytes / size_of::<K::Element>();
-            buf.resize(buf.len() - shrink_by_chars, K::ZERO);
-            Ok(true)
-        } else {
-            // value is NULL
-            buf.clear();
-            Ok(false)
+        None
+    }
+}
+
+/// State of a target after a call to `get_data`. Captured, so the target does no longer borrow the
+/// buffer, which we may need to extend.
+struct FetchedPart {
+    is_complete: bool,
+    indicator: Indicator,
+    len_in_bytes: Option<usize>,
+    capacity_in_bytes: usize,
+}
+
+impl FetchedPart {
+    fn of<K: VarKind>(target: &VarCell<&mut [K::Element], K>) -> Self {
+        Self {
+            is_complete: target.is_complete(),
+            indicator: target.indicator(),
+            len_in_bytes: target.len_in_bytes(),
+            capacity_in_bytes: target.capacity_in_bytes(),
         }
     }
 }
 
+/// Maps errors of `SQLGetData`. Shared between [`CursorRow`] and [`CursorRowPolling`].
+fn get_data_result(result: SqlResult<()>, stmt: &StatementRef<'_>) -> Result<(), Error> {
+    result
+        .into_result(stmt)
+        .provide_context_for_diagnostic(|record, function| {
+            if record.state == State::INDICATOR_VARIABLE_REQUIRED_BUT_NOT_SUPPLIED {
+                Error::UnableToRepresentNull(record)
+            } else {
+                Error::Diagnostics { record, function }
+            }
+        })
+}
+
 /// Cursors are used to process and iterate the result sets returned by executing queries. Created
 /// by either a prepared query or direct execution. Usually utilized through the [`crate::Cursor`]
 /// trait.
@@ -377,6 +473,12 @@ where
         unsafe { ptr::read(&(*self_ptr).statement) }
     }
 
+    /// A handle which can be used to cancel fetching from this cursor from another thread. See
+    /// [`CancelHandle`].
+    pub fn cancel_handle(&mut self) -> CancelHandle {
+        CancelHandle::new(self.as_sys())
+    }
+
     pub(crate) fn as_sys(&mut self) -> HStmt {
         self.as_stmt_ref().as_sys()
     }
@@ -417,6 +519,18 @@ pub unsafe trait RowSetBuffer {
 
     /// Find an indicator larger than the maximum element size of the buffer.
     fn find_truncation(&self) -> Option<TruncationInfo>;
+
+    /// Mutable reference to an array holding the status of each row in the row set. If `Some`, it
+    /// is bound to the statement, so the driver reports the status of each fetched row in it. See
+    /// [`RowStatus`]. `None` by default.
+    ///
+    /// # Safety
+    ///
+    /// Implementations of this method must take care that the returned slice stays valid, even if
+    /// `self` should be moved. It must hold at least [`Self::row_array_size`] elements.
+    fn mut_row_status(&mut self) -> Option<&mut [u16]> {
+        None
+    }
 }
 
 /// Returned by [`RowSetBuffer::find_truncation`]. Contains information about the truncation found.
@@ -448,6 +562,56 @@ unsafe impl<T: RowSetBuffer> RowSetBuffer for &mut T {
     fn find_truncation(&self) -> Option<TruncationInfo> {
         (**self).find_truncation()
     }
+
+    fn mut_row_status(&mut self) -> Option<&mut [u16]> {
+        (*self).mut_row_status()
+    }
+}
+
+/// Status of an individual row in a row set, as reported by the driver. See e.g.
+/// [`crate::buffers::ColumnarBuffer::row_status`] or [`crate::buffers::RowVec::row_status`].
+///
+/// A fetch returning rows with an error, does not fail as a whole. Use the row status to find out
+/// which rows of the batch hold valid values.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RowStatus {
+    /// `SQL_ROW_SUCCESS`. The row has been fetched successfully and has not changed since it has
+    /// been last fetched.
+    Success,
+    /// `SQL_ROW_SUCCESS_WITH_INFO`. The row has been fetched successfully, but the driver returned
+    /// a warning about it, e.g. because a value has been truncated.
+    SuccessWithInfo,
+    /// `SQL_ROW_ERROR`. An error occurred while fetching the row, e.g. because a value could not
+    /// be converted into the type of the buffer. The values of this row are not valid.
+    Error,
+    /// `SQL_ROW_UPDATED`. The row has been updated since it has been last fetched.
+    Updated,
+    /// `SQL_ROW_DELETED`. The row has been deleted since it has been last fetched.
+    Deleted,
+    /// `SQL_ROW_ADDED`. The row has been inserted using `SQLBulkOperations`.
+    Added,
+    /// `SQL_ROW_NOROW`. The row set overlapped the end of the result set and no row was returned
+    /// for this element.
+    NoRow,
+    /// A value not defined by the ODBC standard, e.g. a driver specific row status.
+    Other(u16),
+}
+
+impl RowStatus {
+    /// Converts the value written into the row status array by the driver. Values not defined by
+    /// the ODBC standard are mapped to [`RowStatus::Other`].
+    pub fn from_raw(raw: u16) -> Self {
+        match raw {
+            0 => RowStatus::Success,
+            1 => RowStatus::Deleted,
+            2 => RowStatus::Updated,
+            3 => RowStatus::NoRow,
+            4 => RowStatus::Added,
+            5 => RowStatus::Error,
+            6 => RowStatus::SuccessWithInfo,
+            other => RowStatus::Other(other),
+        }
+    }
 }
 
 /// In order to save on network overhead, it is recommended to use block cursors instead of fetching
@@ -551,6 +715,120 @@ where
         }
     }
 
+    /// Fills the bound buffer with the first row set of the result set. Like all the other
+    /// scrolling methods this requires the cursor to be scrollable. Set
+    /// [`crate::StatementOptions::cursor_type`] or [`crate::StatementOptions::scrollable`] before
+    /// executing the query, otherwise the driver is likely to return an error.
+    ///
+    /// # Return
+    ///
+    /// `None` if the result set is empty. `Some` with a reference to the internal buffer otherwise.
+    ///
+    /// ```no_run
+    /// use odbc_api::{
+    ///     buffers::TextRowSet, Connection, Cursor, CursorType, Error, StatementOptions,
+    /// };
+    ///
+    /// fn print_last_and_first(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     let options = StatementOptions {
+    ///         cursor_type: Some(CursorType::Static),
+    ///         ..StatementOptions::default()
+    ///     };
+    ///     let mut cursor = conn
+    ///         .execute_with_options("SELECT a FROM Numbers ORDER BY a", (), options)?
+    ///         .unwrap();
+    ///     let buffer = TextRowSet::for_cursor(1, &mut cursor, Some(4000))?;
+    ///     let mut cursor = cursor.bind_buffer(buffer)?;
+    ///     if let Some(batch) = cursor.fetch_last()? {
+    ///         println!("Largest: {:?}", batch.at_as_str(0, 0));
+    ///     }
+    ///     if let Some(batch) = cursor.fetch_first()? {
+    ///         println!("Smallest: {:?}", batch.at_as_str(0, 0));
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn fetch_first(&mut self) -> Result<Option<&B>, Error>
+    where
+        B: RowSetBuffer,
+    {
+        self.fetch_scroll(FetchOrientation::First, 0)
+    }
+
+    /// Fills the bound buffer with the last row set of the result set. Requires a scrollable
+    /// cursor. See [`Self::fetch_first`].
+    ///
+    /// # Return
+    ///
+    /// `None` if the result set is empty. `Some` with a reference to the internal buffer otherwise.
+    pub fn fetch_last(&mut self) -> Result<Option<&B>, Error>
+    where
+        B: RowSetBuffer,
+    {
+        self.fetch_scroll(FetchOrientation::Last, 0)
+    }
+
+    /// Fills the bound buffer with the row set preceding the current one. Requires a scrollable
+    /// cursor. See [`Self::fetch_first`].
+    ///
+    /// # Return
+    ///
+    /// `None` if the cursor has been positioned before the start of the result set. `Some` with a
+    /// reference to the internal buffer otherwise.
+    pub fn fetch_prior(&mut self) -> Result<Option<&B>, Error>
+    where
+        B: RowSetBuffer,
+    {
+        self.fetch_scroll(FetchOrientation::Prior, 0)
+    }
+
+    /// Fills the bound buffer with the row set starting at row `row_number`. Rows are counted
+    /// starting with `1`. Negative numbers are counted from the end of the result set, i.e. `-1`
+    /// refers to the last row. `0` positions the cursor before the start of the result set.
+    /// Requires a scrollable cursor. See [`Self::fetch_first`].
+    ///
+    /// # Return
+    ///
+    /// `None` if the cursor has been positioned before the start or after the end of the result
+    /// set. `Some` with a reference to the internal buffer otherwise.
+    pub fn fetch_absolute(&mut self, row_number: isize) -> Result<Option<&B>, Error>
+    where
+        B: RowSetBuffer,
+    {
+        self.fetch_scroll(FetchOrientation::Absolute, row_number)
+    }
+
+    /// Fills the bound buffer with the row set starting `offset` rows from the start of the current
+    /// row set. `offset` may be negative to move backwards. Requires a scrollable cursor. See
+    /// [`Self::fetch_first`].
+    ///
+    /// # Return
+    ///
+    /// `None` if the cursor has been positioned before the start or after the end of the result
+    /// set. `Some` with a reference to the internal buffer otherwise.
+    pub fn fetch_relative(&mut self, offset: isize) -> Result<Option<&B>, Error>
+    where
+        B: RowSetBuffer,
+    {
+        self.fetch_scroll(FetchOrientation::Relative, offset)
+    }
+
+    fn fetch_scroll(
+        &mut self,
+        orientation: FetchOrientation,
+        offset: isize,
+    ) -> Result<Option<&B>, Error>
+    where
+        B: RowSetBuffer,
+    {
+        let mut stmt = self.cursor.as_stmt_ref();
+        unsafe {
+            let result = stmt.fetch_scroll(orientation, offset);
+            let has_row = error_handling_for_fetch(result, stmt, &self.buffer, false)?;
+            Ok(has_row.then_some(&self.buffer))
+        }
+    }
+
     /// Unbinds the buffer from the underlying statement handle. Potential usecases for this
     /// function include.
     ///
@@ -587,6 +865,182 @@ where
     }
 }
 
+impl<C, B> BlockCursor<C, B>
+where
+    C: AsStatementRef,
+{
+    /// A handle which can be used to cancel fetching from this cursor from another thread. See
+    /// [`CancelHandle`].
+    pub fn cancel_handle(&mut self) -> CancelHandle {
+        CancelHandle::new(self.cursor.as_stmt_ref().as_sys())
+    }
+}
+
+impl<C> BlockCursor<C, ColumnarAnyBuffer>
+where
+    C: AsStatementRef,
+{
+    /// Use this method to gain write access to the column buffers bound to the cursor. Change the
+    /// values of the current rowset in order to update them with [`Self::set_pos`], or fill the
+    /// buffer with new rows to insert them with [`Self::bulk_add`].
+    ///
+    /// # Parameters
+    ///
+    /// * `buffer_index`: Zero based index of the column buffer. Not to be confused with the ODBC
+    ///   column index of the result set. See [`ColumnarAnyBuffer::column`].
+    pub fn column_mut(&mut self, buffer_index: usize) -> AnySliceMut<'_> {
+        let (col_index, column) = self.buffer.column_buffer_mut(buffer_index);
+        unsafe { column.slice_mut(BoundAt::Column(col_index), self.cursor.as_stmt_ref()) }
+    }
+
+    /// Positions the cursor on a row of the current rowset and applies `operation` to it. Updating
+    /// or deleting rows requires a concurrency mode other than [`crate::Concurrency::ReadOnly`].
+    /// Set [`crate::StatementOptions::concurrency`] before executing the query. Usually a cursor
+    /// type other than forward only is required, too. See [`crate::StatementOptions::cursor_type`].
+    ///
+    /// # Parameters
+    ///
+    /// * `row_number`: One based position of the row within the current rowset. `0` applies
+    ///   `operation` to every row of the rowset.
+    /// * `operation`: What to do with the row. [`SetPosOperation::Update`] sends the values
+    ///   currently held by the buffer (see [`Self::column_mut`]) to the data source.
+    /// * `lock`: Lock to apply to the row after performing `operation`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::{
+    ///     buffers::{BufferDesc, ColumnarAnyBuffer}, Concurrency, Connection, Cursor, CursorType,
+    ///     Error, LockType, SetPosOperation, StatementOptions,
+    /// };
+    ///
+    /// /// Doubles the first value of each batch and deletes the second one.
+    /// fn double_and_delete(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     let options = StatementOptions {
+    ///         cursor_type: Some(CursorType::KeysetDriven),
+    ///         concurrency: Some(Concurrency::Lock),
+    ///         ..StatementOptions::default()
+    ///     };
+    ///     let cursor = conn.execute_with_options("SELECT a FROM Numbers", (), options)?.unwrap();
+    ///     let buffer = ColumnarAnyBuffer::from_descs(2, [BufferDesc::I32 { nullable: false }]);
+    ///     let mut cursor = cursor.bind_buffer(buffer)?;
+    ///     while let Some(batch) = cursor.fetch()? {
+    ///         if batch.num_rows() < 2 {
+    ///             break;
+    ///         }
+    ///         let values = cursor.column_mut(0).as_slice::<i32>().unwrap();
+    ///         values[0] *= 2;
+    ///         cursor.set_pos(1, SetPosOperation::Update, LockType::NoChange)?;
+    ///         cursor.set_pos(2, SetPosOperation::Delete, LockType::NoChange)?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_pos(
+        &mut self,
+        row_number: usize,
+        operation: SetPosOperation,
+        lock: LockType,
+    ) -> Result<(), Error> {
+        // The driver reads the values to send from the buffer. Truncated values have an indicator
+        // larger than the buffer element and would cause out of bounds reads.
+        if operation == SetPosOperation::Update {
+            self.check_for_truncation(self.buffer.num_rows())?;
+        }
+        let mut stmt = self.cursor.as_stmt_ref();
+        unsafe { stmt.set_pos(row_number, operation.as_sys(), lock.as_sys()) }.into_result(&stmt)
+    }
+
+    /// Inserts the first `num_rows` rows held by the buffer into the table underlying the result
+    /// set. Fill the buffer with the values to insert using [`Self::column_mut`] first. Requires a
+    /// concurrency mode other than [`crate::Concurrency::ReadOnly`]. After adding rows the position
+    /// of the cursor is undefined, fetch a rowset in order to position it again.
+    ///
+    /// This uses `SQLBulkOperations` with `SQL_ADD`.
+    ///
+    /// # Panics
+    ///
+    /// If `num_rows` is `0` or larger than [`Self::row_array_size`].
+    pub fn bulk_add(&mut self, num_rows: usize) -> Result<(), Error> {
+        let capacity = self.buffer.row_array_size();
+        if num_rows == 0 || num_rows > capacity {
+            panic!(
+                "Number of rows to add must be larger than zero and not exceed the number of rows \
+                the buffer can hold."
+            );
+        }
+        self.check_for_truncation(num_rows)?;
+        let mut stmt = self.cursor.as_stmt_ref();
+        unsafe {
+            // The number of rows to add is taken from the row array size.
+            stmt.set_row_array_size(num_rows).into_result(&stmt)?;
+            let result = stmt.bulk_operations(BulkOperation::Add);
+            stmt.set_row_array_size(capacity).into_result(&stmt)?;
+            result.into_result(&stmt)
+        }
+    }
+
+    fn check_for_truncation(&self, num_rows: usize) -> Result<(), Error> {
+        if let Some(TruncationInfo {
+            indicator,
+            buffer_index,
+        }) = self.buffer.find_truncation_in(num_rows)
+        {
+            return Err(Error::TooLargeValueForBuffer {
+                indicator,
+                buffer_index,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Operation performed by [`BlockCursor::set_pos`] on a row of the current rowset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetPosOperation {
+    /// `SQL_POSITION`. Only positions the cursor on the row, e.g. to fetch data from it using
+    /// `SQLGetData`.
+    Position,
+    /// `SQL_REFRESH`. Refreshes the values held by the buffer with the values in the data source.
+    Refresh,
+    /// `SQL_UPDATE`. Updates the row in the data source with the values held by the buffer.
+    Update,
+    /// `SQL_DELETE`. Deletes the row from the data source.
+    Delete,
+}
+
+impl SetPosOperation {
+    fn as_sys(self) -> Operation {
+        match self {
+            SetPosOperation::Position => Operation::POSITION,
+            SetPosOperation::Refresh => Operation::REFRESH,
+            SetPosOperation::Update => Operation::UPDATE,
+            SetPosOperation::Delete => Operation::DELETE,
+        }
+    }
+}
+
+/// Lock applied to a row by [`BlockCursor::set_pos`] after performing the operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockType {
+    /// `SQL_LOCK_NO_CHANGE`. Leaves the row in the same lock state as before.
+    NoChange,
+    /// `SQL_LOCK_EXCLUSIVE`. Locks the row exclusively.
+    Exclusive,
+    /// `SQL_LOCK_UNLOCK`. Unlocks the row.
+    Unlock,
+}
+
+impl LockType {
+    fn as_sys(self) -> Lock {
+        match self {
+            LockType::NoChange => Lock::NO_CHANGE,
+            LockType::Exclusive => Lock::EXCLUSIVE,
+            LockType::Unlock => Lock::UNLOCK,
+        }
+    }
+}
+
 impl<C, B> Drop for BlockCursor<C, B>
 where
     C: AsStatementRef,
@@ -643,6 +1097,158 @@ where
         }
         Ok(BlockCursorPolling::new(row_set_buffer, self))
     }
+
+    /// Asynchronously advances the cursor to the next row in the result set. This is **Slow**.
+    /// Bind a buffer using [`Self::bind_buffer`] instead, for good performance. Asynchronous
+    /// sibling of [`Cursor::next_row`].
+    ///
+    /// Fetching row by row is still useful to stream really large values (e.g. text or binary
+    /// large objects) which can not be bound to a buffer upfront. See
+    /// [`CursorRowPolling::get_text`] and [`CursorRowPolling::get_binary`].
+    ///
+    /// ```
+    /// use odbc_api::{CursorPolling, Error, handles::StatementImpl};
+    /// use std::time::Duration;
+    ///
+    /// /// Fetches the text in the first column of each row.
+    /// async fn fetch_all_texts(
+    ///     mut cursor: CursorPolling<StatementImpl<'_>>,
+    /// ) -> Result<Vec<String>, Error> {
+    ///     // Poll every 50 ms.
+    ///     let sleep = || tokio::time::sleep(Duration::from_millis(50));
+    ///     let mut texts = Vec::new();
+    ///     let mut buf = Vec::new();
+    ///     while let Some(mut row) = cursor.next_row(sleep).await? {
+    ///         row.get_text(1, &mut buf, sleep).await?;
+    ///         texts.push(String::from_utf8(buf.clone()).unwrap());
+    ///     }
+    ///     Ok(texts)
+    /// }
+    /// ```
+    pub async fn next_row(
+        &mut self,
+        mut sleep: impl Sleep,
+    ) -> Result<Option<CursorRowPolling<'_>>, Error> {
+        let mut stmt = self.statement.as_stmt_ref();
+        let handle = stmt.as_sys();
+        let result = unsafe { wait_for(handle, || stmt.fetch(), &mut sleep).await };
+        let row_available = result.into_result_bool(&stmt)?;
+        let ret = if row_available {
+            Some(unsafe { CursorRowPolling::new(stmt) })
+        } else {
+            None
+        };
+        Ok(ret)
+    }
+}
+
+/// An individual row of a result set, fetched asynchronously. See [`CursorPolling::next_row`].
+/// Asynchronous sibling of [`CursorRow`].
+pub struct CursorRowPolling<'s> {
+    statement: StatementRef<'s>,
+}
+
+impl<'s> CursorRowPolling<'s> {
+    /// # Safety
+    ///
+    /// `statement` must be in a cursor state and have asynchronous mode enabled.
+    unsafe fn new(statement: StatementRef<'s>) -> Self {
+        CursorRowPolling { statement }
+    }
+
+    /// Asynchronously fills a suitable target buffer with a field from the current row of the
+    /// result set. See [`CursorRow::get_data`].
+    pub async fn get_data(
+        &mut self,
+        col_or_param_num: u16,
+        target: &mut (impl CElement + CDataMut),
+        mut sleep: impl Sleep,
+    ) -> Result<(), Error> {
+        self.get_data_with(col_or_param_num, target, &mut sleep)
+            .await
+    }
+
+    /// Asynchronously retrieves arbitrary large character data from the row and stores it in the
+    /// buffer. Column index starts at `1`. See [`CursorRow::get_text`].
+    ///
+    /// # Return
+    ///
+    /// `true` indicates that the value has not been `NULL` and the value has been placed in `buf`.
+    /// `false` indicates that the value is `NULL`. The buffer is cleared in that case.
+    pub async fn get_text(
+        &mut self,
+        col_or_param_num: u16,
+        buf: &mut Vec<u8>,
+        sleep: impl Sleep,
+    ) -> Result<bool, Error> {
+        self.get_variadic::<Text>(col_or_param_num, buf, sleep)
+            .await
+    }
+
+    /// Asynchronously retrieves arbitrary large character data from the row and stores it in the
+    /// buffer. Column index starts at `1`. The used encoding is UTF-16. See
+    /// [`CursorRow::get_wide_text`].
+    ///
+    /// # Return
+    ///
+    /// `true` indicates that the value has not been `NULL` and the value has been placed in `buf`.
+    /// `false` indicates that the value is `NULL`. The buffer is cleared in that case.
+    pub async fn get_wide_text(
+        &mut self,
+        col_or_param_num: u16,
+        buf: &mut Vec<u16>,
+        sleep: impl Sleep,
+    ) -> Result<bool, Error> {
+        self.get_variadic::<WideText>(col_or_param_num, buf, sleep)
+            .await
+    }
+
+    /// Asynchronously retrieves arbitrary large binary data from the row and stores it in the
+    /// buffer. Column index starts at `1`. See [`CursorRow::get_binary`].
+    ///
+    /// # Return
+    ///
+    /// `true` indicates that the value has not been `NULL` and the value has been placed in `buf`.
+    /// `false` indicates that the value is `NULL`. The buffer is cleared in that case.
+    pub async fn get_binary(
+        &mut self,
+        col_or_param_num: u16,
+        buf: &mut Vec<u8>,
+        sleep: impl Sleep,
+    ) -> Result<bool, Error> {
+        self.get_variadic::<Binary>(col_or_param_num, buf, sleep)
+            .await
+    }
+
+    async fn get_variadic<K: VarKind>(
+        &mut self,
+        col_or_param_num: u16,
+        buf: &mut Vec<K::Element>,
+        mut sleep: impl Sleep,
+    ) -> Result<bool, Error> {
+        let mut retrieval = VarDataRetrieval::<K>::new(buf);
+        loop {
+            let mut target = retrieval.target(buf);
+            self.get_data_with(col_or_param_num, &mut target, &mut sleep)
+                .await?;
+            let part = FetchedPart::of(&target);
+            if let Some(not_null) = retrieval.process(part, buf) {
+                return Ok(not_null);
+            }
+        }
+    }
+
+    async fn get_data_with(
+        &mut self,
+        col_or_param_num: u16,
+        target: &mut (impl CElement + CDataMut),
+        sleep: &mut impl Sleep,
+    ) -> Result<(), Error> {
+        let handle = self.statement.as_sys();
+        let stmt = &mut self.statement;
+        let result = wait_for(handle, || stmt.get_data(col_or_param_num, target), sleep).await;
+        get_data_result(result, &self.statement)
+    }
 }
 
 impl<S> AsStatementRef for CursorPolling<S>
@@ -721,10 +1327,66 @@ where
         B: RowSetBuffer,
     {
         let mut stmt = self.cursor.as_stmt_ref();
-        let result = unsafe { wait_for(|| stmt.fetch(), &mut sleep).await };
+        let handle = stmt.as_sys();
+        let result = unsafe { wait_for(handle, || stmt.fetch(), &mut sleep).await };
         let has_row = error_handling_for_fetch(result, stmt, &self.buffer, error_for_truncation)?;
         Ok(has_row.then_some(&self.buffer))
     }
+
+    /// Unbinds the buffer from the underlying statement handle. Asynchronous sibling of
+    /// [`BlockCursor::unbind`].
+    pub fn unbind(self) -> Result<(C, B), Error> {
+        // In this method we want to deconstruct self and move cursor out of it. We need to
+        // negotiate with the compiler a little bit though, since BlockCursorPolling does implement
+        // `Drop`.
+        let dont_drop_me = MaybeUninit::new(self);
+        let self_ptr = dont_drop_me.as_ptr();
+
+        // Safety: We know `dont_drop_me` is valid at this point so reading the ptr is okay
+        let mut cursor = unsafe { ptr::read(&(*self_ptr).cursor) };
+        let buffer = unsafe { ptr::read(&(*self_ptr).buffer) };
+
+        // Now that we have cursor out of block cursor, we need to unbind the buffer.
+        unbind_buffer_from_cursor(&mut cursor)?;
+
+        Ok((cursor, buffer))
+    }
+
+    /// The buffer bound to the cursor.
+    #[cfg(feature = "futures")]
+    pub(crate) fn row_array(&self) -> &B {
+        &self.buffer
+    }
+
+    /// Calls `SQLFetch` once, without waiting for it to complete. `None` if the fetch is still
+    /// executing and this method must be called again. Building block for implementations of
+    /// `poll`-style interfaces, which can not await [`Self::fetch`].
+    #[cfg(feature = "futures")]
+    pub(crate) fn poll_fetch(&mut self, error_for_truncation: bool) -> Option<Result<bool, Error>>
+    where
+        B: RowSetBuffer,
+    {
+        let mut stmt = self.cursor.as_stmt_ref();
+        let result = unsafe { stmt.fetch() };
+        if result == SqlResult::StillExecuting {
+            return None;
+        }
+        Some(error_handling_for_fetch(
+            result,
+            stmt,
+            &self.buffer,
+            error_for_truncation,
+        ))
+    }
+
+    /// Cancels a fetch started with [`Self::poll_fetch`], which is still executing, and blocks
+    /// until the driver confirms the cancellation.
+    #[cfg(feature = "futures")]
+    pub(crate) fn cancel_fetch(&mut self) {
+        let mut stmt = self.cursor.as_stmt_ref();
+        let handle = stmt.as_sys();
+        cancel_and_wait(|| cancel_statement(handle), || unsafe { stmt.fetch() });
+    }
 }
 
 /// Binds a row set buffer to a statment. Implementation is shared between synchronous and
@@ -750,6 +1412,9 @@ unsafe fn bind_row_set_buffer_to_statement(
         })?;
     stmt.set_num_rows_fetched(row_set_buffer.mut_num_fetch_rows())
         .into_result(&stmt)?;
+    if let Some(row_status) = row_set_buffer.mut_row_status() {
+        stmt.set_row_status(row_status).into_result(&stmt)?;
+    }
     row_set_buffer.bind_colmuns_to_cursor(stmt)?;
     Ok(())
 }
@@ -819,5 +1484,6 @@ fn unbind_buffer_from_cursor(cursor: &mut impl AsStatementRef) -> Result<(), Err
     let mut stmt = cursor.as_stmt_ref();
     stmt.unbind_cols().into_result(&stmt)?;
     stmt.unset_num_rows_fetched().into_result(&stmt)?;
+    stmt.unset_row_status().into_result(&stmt)?;
     Ok(())
 }
@@ -7,7 +7,8 @@ use crate::{
         self, log_diagnostics, slice_to_utf8, OutputStringBuffer, SqlChar, SqlResult, SqlText,
         State, SzBuffer,
     },
-    Connection, DriverCompleteOption, Error,
+    sleep::wait_for_connection,
+    Connection, DriverCompleteOption, Error, Sleep,
 };
 use log::debug;
 use odbc_sys::{AttrCpMatch, AttrOdbcVersion, FetchOrientation, HWnd};
@@ -282,6 +283,50 @@ impl Environment {
         Ok(Connection::new(connection))
     }
 
+    /// Asynchronous sibling of [`Self::connect_with_connection_string`]. Uses polling mode to be
+    /// asynchronous. `sleep` does govern the behaviour of polling, by waiting for the future in
+    /// between polling. Sleep should not be implemented using a sleep which blocks the system
+    /// thread, but rather utilize the methods provided by your async runtime. E.g.:
+    ///
+    /// ```no_run
+    /// use odbc_api::{ConnectionOptions, Connection, Environment, Error};
+    /// use std::time::Duration;
+    ///
+    /// async fn connect<'e>(
+    ///     env: &'e Environment,
+    ///     connection_string: &str,
+    /// ) -> Result<Connection<'e>, Error> {
+    ///     // Poll every 50 ms.
+    ///     let sleep = || tokio::time::sleep(Duration::from_millis(50));
+    ///     env.connect_polling(connection_string, ConnectionOptions::default(), sleep).await
+    /// }
+    /// ```
+    ///
+    /// **Attention**: This feature requires driver support for asynchronous execution on
+    /// connection level (`SQL_ATTR_ASYNC_DBC_FUNCTIONS_ENABLE`). If the driver does not support it,
+    /// or the `odbc_version_3_80` feature is not active, the connection is established blocking.
+    /// The function will still return the correct result, just be aware that it may block until it
+    /// is finished.
+    pub async fn connect_polling(
+        &self,
+        connection_string: &str,
+        options: ConnectionOptions,
+        mut sleep: impl Sleep,
+    ) -> Result<Connection<'_>, Error> {
+        let connection_string = SqlText::new(connection_string);
+        let mut connection = self.allocate_connection()?;
+
+        options.apply(&connection)?;
+
+        wait_for_connection(
+            &mut connection,
+            |connection| connection.connect_with_connection_string(&connection_string),
+            &mut sleep,
+        )
+        .await?;
+        Ok(Connection::new(connection))
+    }
+
     /// Allocates a connection handle and establishes connections to a driver and a data source.
     ///
     /// An alternative to `connect` and `connect_with_connection_string`. This method can be
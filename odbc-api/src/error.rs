@@ -1,8 +1,8 @@
-use std::io;
+use std::{io, time::Duration};
 
 use thiserror::Error as ThisError;
 
-use crate::handles::{log_diagnostics, Diagnostics, Record as DiagnosticRecord, SqlResult};
+use crate::handles::{log_diagnostics, Diagnostics, Record as DiagnosticRecord, SqlResult, State};
 
 /// Error indicating a failed allocation for a column buffer
 #[derive(Debug)]
@@ -134,6 +134,111 @@ pub enum Error {
         /// Index of the buffer in which the truncation occurred.
         buffer_index: usize,
     },
+    /// Emitted by [`crate::ConnectionPool::get`] if no connection became available within the
+    /// checkout timeout specified in [`crate::PoolOptions`].
+    #[error(
+        "Timed out waiting for a connection from the connection pool. All connections are checked \
+        out and the pool is already at its maximum size. Timeout: {timeout:?}"
+    )]
+    ConnectionPoolTimeout {
+        /// The checkout timeout which elapsed.
+        timeout: Duration,
+    },
+    /// Emitted by [`crate::Transaction::savepoint`] if the driver reports that it does not support
+    /// transactions at all.
+    #[error(
+        "Savepoints are not supported by the data source. The driver reports that transactions \
+        are not supported."
+    )]
+    SavepointsNotSupported,
+    /// A timeout expired before the data source completed the operation. Mapped from SQLSTATE
+    /// `HYT00`. Executing or fetching from a statement reports this if the query timeout specified
+    /// in [`crate::StatementOptions::query_timeout_sec`] expires, but other functions, e.g. those
+    /// establishing a connection, may report it too if their respective timeout expired.
+    #[error(
+        "The timeout expired before the data source completed the operation. Diagnostic record \
+        returned by {function}:\n{record}"
+    )]
+    Timeout {
+        /// Diagnostic record returned by the ODBC driver manager
+        record: DiagnosticRecord,
+        /// ODBC API call which produced the diagnostic record
+        function: &'static str,
+    },
+    /// The operation has been canceled, e.g. using [`crate::CancelHandle::cancel`]. Mapped from
+    /// SQLSTATE `HY008`.
+    #[error(
+        "The operation has been canceled. Diagnostic record returned by {function}:\n{record}"
+    )]
+    Cancelled {
+        /// Diagnostic record returned by the ODBC driver manager
+        record: DiagnosticRecord,
+        /// ODBC API call which produced the diagnostic record
+        function: &'static str,
+    },
+    /// A `NULL` value has been fetched using [`crate::CursorRow::get`] into a type which can not
+    /// represent `NULL`.
+    #[error(
+        "Column {column} is NULL, yet the requested type `{target_type}` can not represent NULL. \
+        Fetch the column into an `Option` instead."
+    )]
+    UnexpectedNull {
+        /// One based index of the column.
+        column: u16,
+        /// Name of the type the value has been requested as.
+        target_type: &'static str,
+    },
+    /// The result set has fewer columns than bound by a [`crate::buffers::FetchRow`]. See
+    /// [`crate::buffers::FetchRow::column_count`].
+    #[error(
+        "The row type binds columns up to index {expected}, but the result set has only {actual} \
+        columns."
+    )]
+    ColumnCountMismatch {
+        /// Highest index of the columns bound by the row type.
+        expected: u16,
+        /// Number of columns in the result set.
+        actual: u16,
+    },
+    /// The result set does not contain a column for each field of a [`crate::FromRow`] type.
+    #[error(
+        "The result set has no columns named {missing:?}, which are required to fetch rows as \
+        `{row_type}`. Columns in the result set are: {available:?}"
+    )]
+    ColumnNameMismatch {
+        /// Name of the type the rows have been requested as.
+        row_type: &'static str,
+        /// Names of the fields without a matching column.
+        missing: Vec<String>,
+        /// Names of all columns in the result set.
+        available: Vec<String>,
+    },
+    /// The value of a column could not be converted into the type requested using
+    /// [`crate::CursorRow::get`].
+    #[error("Column {column} can not be fetched as `{target_type}`: {reason}")]
+    IncompatibleColumnType {
+        /// One based index of the column.
+        column: u16,
+        /// Name of the type the value has been requested as.
+        target_type: &'static str,
+        /// Why the conversion failed. Usually the diagnostic record returned by the driver.
+        reason: String,
+    },
+    /// A buffer handed back to a stream of batches using `BlockCursorStream::fill` differs in
+    /// capacity, column indices or column types from the buffers of the stream.
+    #[error(
+        "The buffer handed back to the stream does not have the same capacity, column indices and \
+        column types as the buffers of the stream."
+    )]
+    BufferLayoutMismatch,
+    /// [`crate::Prepared::set_options`] has been called with options which can only be applied
+    /// before the statement is prepared. Pass these to [`crate::Connection::prepare_with_options`]
+    /// instead.
+    #[error(
+        "The cursor type, scrollability and concurrency of a statement can not be changed once it \
+        has been prepared. Pass them to `Connection::prepare_with_options` instead."
+    )]
+    CursorOptionsAfterPrepare,
 }
 
 impl Error {
@@ -225,7 +330,11 @@ impl<T> SqlResult<T> {
                 let mut record = DiagnosticRecord::with_capacity(512);
                 if record.fill_from(handle, 1) {
                     log_diagnostics(handle);
-                    Err(Error::Diagnostics { record, function })
+                    match record.state {
+                        State::TIMEOUT_EXPIRED => Err(Error::Timeout { record, function }),
+                        State::OPERATION_CANCELED => Err(Error::Cancelled { record, function }),
+                        _ => Err(Error::Diagnostics { record, function }),
+                    }
                 } else {
                     // Anecdotal ways to reach this code paths:
                     //
@@ -4,7 +4,7 @@ use crate::{
     handles::{AsStatementRef, SqlText, Statement},
     parameter::Blob,
     sleep::wait_for,
-    CursorImpl, CursorPolling, Error, ParameterCollectionRef, Sleep,
+    CursorImpl, CursorPolling, Error, ParameterCollectionRef, RowIdScope, RowIdentifierType, Sleep,
 };
 
 /// Shared implementation for executing a query with parameters between [`crate::Connection`],
@@ -144,12 +144,13 @@ where
     S: AsStatementRef,
 {
     let mut stmt = statement.as_stmt_ref();
+    let handle = stmt.as_sys();
     let result = if let Some(sql) = query {
         // We execute an unprepared "one shot query"
-        wait_for(|| stmt.exec_direct(sql), &mut sleep).await
+        wait_for(handle, || stmt.exec_direct(sql), &mut sleep).await
     } else {
         // We execute a prepared query
-        wait_for(|| stmt.execute(), &mut sleep).await
+        wait_for(handle, || stmt.execute(), &mut sleep).await
     };
 
     // If delayed parameters (e.g. input streams) are bound we might need to put data in order to
@@ -167,14 +168,14 @@ where
             let blob_ref = &mut *blob_ptr;
             // Loop over all batches within each blob
             while let Some(batch) = blob_ref.next_batch().map_err(Error::FailedReadingInput)? {
-                let result = wait_for(|| stmt.put_binary_batch(batch), &mut sleep).await;
+                let result = wait_for(handle, || stmt.put_binary_batch(batch), &mut sleep).await;
                 result.into_result(&stmt)?;
             }
         }
     }
 
     // Check if a result set has been created.
-    let num_result_cols = wait_for(|| stmt.num_result_cols(), &mut sleep)
+    let num_result_cols = wait_for(handle, || stmt.num_result_cols(), &mut sleep)
         .await
         .into_result(&stmt)?;
     if num_result_cols == 0 {
@@ -271,3 +272,202 @@ where
 
     Ok(cursor)
 }
+
+/// Shared implementation for executing a primary keys query between [`crate::Connection`] and
+/// [`crate::Preallocated`].
+pub fn execute_primary_keys<S>(
+    mut statement: S,
+    catalog_name: &SqlText,
+    schema_name: &SqlText,
+    table_name: &SqlText,
+) -> Result<CursorImpl<S>, Error>
+where
+    S: AsStatementRef,
+{
+    let mut stmt = statement.as_stmt_ref();
+
+    stmt.primary_keys(catalog_name, schema_name, table_name)
+        .into_result(&stmt)?;
+
+    // We assume primary keys always creates a result set, since it works like a SELECT statement.
+    debug_assert_ne!(stmt.num_result_cols().unwrap(), 0);
+
+    // Safe: `statement` is in Cursor state.
+    let cursor = unsafe { CursorImpl::new(statement) };
+
+    Ok(cursor)
+}
+
+/// Shared implementation for executing a statistics query between [`crate::Connection`] and
+/// [`crate::Preallocated`].
+pub fn execute_statistics<S>(
+    mut statement: S,
+    catalog_name: &SqlText,
+    schema_name: &SqlText,
+    table_name: &SqlText,
+    unique_only: bool,
+    ensure_accuracy: bool,
+) -> Result<CursorImpl<S>, Error>
+where
+    S: AsStatementRef,
+{
+    let mut stmt = statement.as_stmt_ref();
+
+    stmt.statistics(
+        catalog_name,
+        schema_name,
+        table_name,
+        unique_only,
+        ensure_accuracy,
+    )
+    .into_result(&stmt)?;
+
+    // We assume statistics always creates a result set, since it works like a SELECT statement.
+    debug_assert_ne!(stmt.num_result_cols().unwrap(), 0);
+
+    // Safe: `statement` is in Cursor state.
+    let cursor = unsafe { CursorImpl::new(statement) };
+
+    Ok(cursor)
+}
+
+/// Shared implementation for executing a procedures query between [`crate::Connection`] and
+/// [`crate::Preallocated`].
+pub fn execute_procedures<S>(
+    mut statement: S,
+    catalog_name: &SqlText,
+    schema_name: &SqlText,
+    procedure_name: &SqlText,
+) -> Result<CursorImpl<S>, Error>
+where
+    S: AsStatementRef,
+{
+    let mut stmt = statement.as_stmt_ref();
+
+    stmt.procedures(catalog_name, schema_name, procedure_name)
+        .into_result(&stmt)?;
+
+    // We assume procedures always creates a result set, since it works like a SELECT statement.
+    debug_assert_ne!(stmt.num_result_cols().unwrap(), 0);
+
+    // Safe: `statement` is in Cursor state.
+    let cursor = unsafe { CursorImpl::new(statement) };
+
+    Ok(cursor)
+}
+
+/// Shared implementation for executing a procedure columns query between [`crate::Connection`]
+/// and [`crate::Preallocated`].
+pub fn execute_procedure_columns<S>(
+    mut statement: S,
+    catalog_name: &SqlText,
+    schema_name: &SqlText,
+    procedure_name: &SqlText,
+    column_name: &SqlText,
+) -> Result<CursorImpl<S>, Error>
+where
+    S: AsStatementRef,
+{
+    let mut stmt = statement.as_stmt_ref();
+
+    stmt.procedure_columns(catalog_name, schema_name, procedure_name, column_name)
+        .into_result(&stmt)?;
+
+    // We assume procedure columns always creates a result set, since it works like a SELECT
+    // statement.
+    debug_assert_ne!(stmt.num_result_cols().unwrap(), 0);
+
+    // Safe: `statement` is in Cursor state.
+    let cursor = unsafe { CursorImpl::new(statement) };
+
+    Ok(cursor)
+}
+
+/// Shared implementation for executing a special columns query between [`crate::Connection`] and
+/// [`crate::Preallocated`].
+pub fn execute_special_columns<S>(
+    mut statement: S,
+    identifier_type: RowIdentifierType,
+    catalog_name: &SqlText,
+    schema_name: &SqlText,
+    table_name: &SqlText,
+    scope: RowIdScope,
+    nullable: bool,
+) -> Result<CursorImpl<S>, Error>
+where
+    S: AsStatementRef,
+{
+    let mut stmt = statement.as_stmt_ref();
+
+    stmt.special_columns(
+        identifier_type as u16,
+        catalog_name,
+        schema_name,
+        table_name,
+        scope as u16,
+        nullable,
+    )
+    .into_result(&stmt)?;
+
+    // We assume special columns always creates a result set, since it works like a SELECT
+    // statement.
+    debug_assert_ne!(stmt.num_result_cols().unwrap(), 0);
+
+    // Safe: `statement` is in Cursor state.
+    let cursor = unsafe { CursorImpl::new(statement) };
+
+    Ok(cursor)
+}
+
+/// Shared implementation for executing a table privileges query between [`crate::Connection`] and
+/// [`crate::Preallocated`].
+pub fn execute_table_privileges<S>(
+    mut statement: S,
+    catalog_name: &SqlText,
+    schema_name: &SqlText,
+    table_name: &SqlText,
+) -> Result<CursorImpl<S>, Error>
+where
+    S: AsStatementRef,
+{
+    let mut stmt = statement.as_stmt_ref();
+
+    stmt.table_privileges(catalog_name, schema_name, table_name)
+        .into_result(&stmt)?;
+
+    // We assume table privileges always creates a result set, since it works like a SELECT
+    // statement.
+    debug_assert_ne!(stmt.num_result_cols().unwrap(), 0);
+
+    // Safe: `statement` is in Cursor state.
+    let cursor = unsafe { CursorImpl::new(statement) };
+
+    Ok(cursor)
+}
+
+/// Shared implementation for executing a column privileges query between [`crate::Connection`]
+/// and [`crate::Preallocated`].
+pub fn execute_column_privileges<S>(
+    mut statement: S,
+    catalog_name: &SqlText,
+    schema_name: &SqlText,
+    table_name: &SqlText,
+    column_name: &SqlText,
+) -> Result<CursorImpl<S>, Error>
+where
+    S: AsStatementRef,
+{
+    let mut stmt = statement.as_stmt_ref();
+
+    stmt.column_privileges(catalog_name, schema_name, table_name, column_name)
+        .into_result(&stmt)?;
+
+    // We assume column privileges always creates a result set, since it works like a SELECT
+    // statement.
+    debug_assert_ne!(stmt.num_result_cols().unwrap(), 0);
+
+    // Safe: `statement` is in Cursor state.
+    let cursor = unsafe { CursorImpl::new(statement) };
+
+    Ok(cursor)
+}
@@ -0,0 +1,157 @@
+use std::{any::type_name, marker::PhantomData};
+
+use crate::{Cursor, CursorRow, Error, ResultSetMetadata};
+
+/// Types which can be created from an entire row of a result set, mapping their fields to columns
+/// by name. Usually implemented using `#[derive(FromRow)]` (requires the `derive` feature). Use
+/// [`Cursor::rows_as`] to fetch rows of this type.
+///
+/// In contrast to [`crate::buffers::FetchRow`] rows are fetched one by one, using
+/// [`crate::FromSql`] for each field. This allows for variadic fields like [`String`] or `Vec<u8>`
+/// and does not depend on the order of the columns in the result set, at the cost of performance.
+///
+/// # Example
+///
+/// Usually you would derive this trait. Implementing it manually looks like this:
+///
+/// ```no_run
+/// use odbc_api::{Connection, Cursor, CursorRow, Error, FromRow};
+///
+/// struct Person {
+///     name: String,
+///     age: Option<i16>,
+/// }
+///
+/// impl FromRow for Person {
+///     fn column_names() -> &'static [&'static str] {
+///         &["name", "age"]
+///     }
+///
+///     fn from_row(row: &mut CursorRow<'_>, columns: &[u16]) -> Result<Self, Error> {
+///         // Read the fields in ascending column order.
+///         let (name, age) = if columns[0] < columns[1] {
+///             let name = row.get(columns[0])?;
+///             (name, row.get(columns[1])?)
+///         } else {
+///             let age = row.get(columns[1])?;
+///             (row.get(columns[0])?, age)
+///         };
+///         Ok(Person { name, age })
+///     }
+/// }
+///
+/// fn print_persons(conn: &Connection<'_>) -> Result<(), Error> {
+///     let cursor = conn
+///         .execute("SELECT age, name FROM Persons", ())?
+///         .expect("SELECT statement must produce a cursor");
+///     for person in cursor.rows_as::<Person>() {
+///         let person = person?;
+///         match person.age {
+///             Some(age) => println!("{} is {age} years old", person.name),
+///             None => println!("{}", person.name),
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+pub trait FromRow: Sized {
+    /// Names of the result set columns the fields are read from. One name for each field.
+    fn column_names() -> &'static [&'static str];
+
+    /// Reads the fields from the current row. `columns` holds the one based index of the column for
+    /// each name in [`Self::column_names`].
+    ///
+    /// Implementations should read the fields in ascending column order, since many drivers only
+    /// support retrieving the fields of a row in that order.
+    fn from_row(row: &mut CursorRow<'_>, columns: &[u16]) -> Result<Self, Error>;
+}
+
+/// Fetches the rows of a cursor one by one as values of type `R`. Created using
+/// [`Cursor::rows_as`].
+///
+/// The columns of the result set are mapped to the fields of `R` upon the first call to
+/// [`Self::fetch`]. Column names are compared case insensitive if there is no exact match.
+pub struct FromRowCursor<C, R> {
+    cursor: C,
+    /// Column index for each name in [`FromRow::column_names`]. `None` until the first fetch.
+    columns: Option<Vec<u16>>,
+    _row: PhantomData<fn() -> R>,
+}
+
+impl<C, R> FromRowCursor<C, R>
+where
+    C: Cursor,
+    R: FromRow,
+{
+    pub(crate) fn new(cursor: C) -> Self {
+        Self {
+            cursor,
+            columns: None,
+            _row: PhantomData,
+        }
+    }
+
+    /// Fetches the next row of the result set. `None` if the result set is consumed.
+    ///
+    /// # Return
+    ///
+    /// [`Error::ColumnNameMismatch`] if the result set does not contain a column for each field of
+    /// `R`. This is checked once, before the first row is fetched.
+    pub fn fetch(&mut self) -> Result<Option<R>, Error> {
+        if self.columns.is_none() {
+            self.columns = Some(map_columns::<R>(&mut self.cursor)?);
+        }
+        let columns = self.columns.as_deref().unwrap();
+        match self.cursor.next_row()? {
+            Some(mut row) => R::from_row(&mut row, columns).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Gives back the underlying cursor, e.g. to call [`Cursor::more_results`].
+    pub fn into_cursor(self) -> C {
+        self.cursor
+    }
+}
+
+impl<C, R> Iterator for FromRowCursor<C, R>
+where
+    C: Cursor,
+    R: FromRow,
+{
+    type Item = Result<R, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.fetch().transpose()
+    }
+}
+
+/// Finds the index of the column for each name in [`FromRow::column_names`].
+fn map_columns<R: FromRow>(cursor: &mut impl ResultSetMetadata) -> Result<Vec<u16>, Error> {
+    let available = cursor.column_names()?.collect::<Result<Vec<_>, _>>()?;
+    let mut missing = Vec::new();
+    let mut columns = Vec::with_capacity(R::column_names().len());
+    for &name in R::column_names() {
+        let position = available
+            .iter()
+            .position(|column| column == name)
+            .or_else(|| {
+                available
+                    .iter()
+                    .position(|column| column.eq_ignore_ascii_case(name))
+            });
+        match position {
+            Some(index) => columns.push((index + 1).try_into().unwrap()),
+            None => missing.push(name.to_owned()),
+        }
+    }
+    if missing.is_empty() {
+        Ok(columns)
+    } else {
+        Err(Error::ColumnNameMismatch {
+            row_type: type_name::<R>(),
+            missing,
+            available,
+        })
+    }
+}
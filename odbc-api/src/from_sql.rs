@@ -0,0 +1,159 @@
+use std::any::type_name;
+
+use odbc_sys::{Date, Time, Timestamp};
+
+use crate::{fixed_sized::Pod, handles::State, Bit, CursorRow, Error, Nullable};
+
+/// Types which can be read from a field of the current row of a result set using
+/// [`CursorRow::get`]. Implemented for integers, floats, `bool`, [`String`], `Vec<u8>`,
+/// [`Date`], [`Time`], [`Timestamp`] and [`Option`] of any of these.
+///
+/// # Example
+///
+/// ```no_run
+/// use odbc_api::{Connection, Cursor, Error};
+///
+/// fn print_birthdays(conn: &Connection<'_>) -> Result<(), Error> {
+///     let mut cursor = conn
+///         .execute("SELECT name, year FROM Birthdays", ())?
+///         .expect("SELECT statement must produce a cursor");
+///     while let Some(mut row) = cursor.next_row()? {
+///         let name: String = row.get(1)?;
+///         let year: Option<i16> = row.get(2)?;
+///         match year {
+///             Some(year) => println!("{name} has been born in {year}"),
+///             None => println!("We do not know when {name} has been born"),
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+pub trait FromSql: Sized {
+    /// Reads the field `col_or_param_num` of the current row. `None` if the field is `NULL`.
+    /// Column index starts at `1`. Variadic values, like text or binary data, are read completely,
+    /// regardless of their size.
+    fn from_sql(row: &mut CursorRow<'_>, col_or_param_num: u16) -> Result<Option<Self>, Error>;
+
+    /// Called by [`CursorRow::get`] if the field is `NULL`. By default this returns
+    /// [`Error::UnexpectedNull`]. Types able to represent `NULL`, like [`Option`], overwrite this.
+    fn from_null(col_or_param_num: u16) -> Result<Self, Error> {
+        Err(Error::UnexpectedNull {
+            column: col_or_param_num,
+            target_type: type_name::<Self>(),
+        })
+    }
+}
+
+impl<T> FromSql for Option<T>
+where
+    T: FromSql,
+{
+    fn from_sql(row: &mut CursorRow<'_>, col_or_param_num: u16) -> Result<Option<Self>, Error> {
+        T::from_sql(row, col_or_param_num).map(Some)
+    }
+
+    fn from_null(_col_or_param_num: u16) -> Result<Self, Error> {
+        Ok(None)
+    }
+}
+
+macro_rules! impl_from_sql_pod {
+    ($t:ty) => {
+        impl FromSql for $t {
+            fn from_sql(
+                row: &mut CursorRow<'_>,
+                col_or_param_num: u16,
+            ) -> Result<Option<Self>, Error> {
+                fixed_sized(row, col_or_param_num)
+            }
+        }
+    };
+}
+
+impl_from_sql_pod!(i8);
+impl_from_sql_pod!(u8);
+impl_from_sql_pod!(i16);
+impl_from_sql_pod!(u16);
+impl_from_sql_pod!(i32);
+impl_from_sql_pod!(u32);
+impl_from_sql_pod!(i64);
+impl_from_sql_pod!(u64);
+impl_from_sql_pod!(f32);
+impl_from_sql_pod!(f64);
+impl_from_sql_pod!(Date);
+impl_from_sql_pod!(Time);
+impl_from_sql_pod!(Timestamp);
+impl_from_sql_pod!(Bit);
+
+impl FromSql for bool {
+    fn from_sql(row: &mut CursorRow<'_>, col_or_param_num: u16) -> Result<Option<Self>, Error> {
+        let bit: Option<Bit> = fixed_sized(row, col_or_param_num)?;
+        Ok(bit.map(|bit| bit.0 != 0))
+    }
+}
+
+impl FromSql for String {
+    fn from_sql(row: &mut CursorRow<'_>, col_or_param_num: u16) -> Result<Option<Self>, Error> {
+        // Ask for UTF-16 independent of the `narrow` feature, so we can decode the text without
+        // knowing the system encoding.
+        let mut buf = Vec::new();
+        let not_null = row
+            .get_wide_text(col_or_param_num, &mut buf)
+            .map_err(|error| incompatible_column_type::<Self>(error, col_or_param_num))?;
+        if !not_null {
+            return Ok(None);
+        }
+        String::from_utf16(&buf)
+            .map(Some)
+            .map_err(|_| Error::IncompatibleColumnType {
+                column: col_or_param_num,
+                target_type: type_name::<Self>(),
+                reason: "The text returned by the driver is not valid UTF-16.".to_owned(),
+            })
+    }
+}
+
+impl FromSql for Vec<u8> {
+    fn from_sql(row: &mut CursorRow<'_>, col_or_param_num: u16) -> Result<Option<Self>, Error> {
+        let mut buf = Vec::new();
+        let not_null = row
+            .get_binary(col_or_param_num, &mut buf)
+            .map_err(|error| incompatible_column_type::<Self>(error, col_or_param_num))?;
+        Ok(not_null.then_some(buf))
+    }
+}
+
+/// Reads a fixed sized value using a single call to `SQLGetData`.
+fn fixed_sized<T>(row: &mut CursorRow<'_>, col_or_param_num: u16) -> Result<Option<T>, Error>
+where
+    T: Pod,
+{
+    let mut value = Nullable::<T>::null();
+    row.get_data(col_or_param_num, &mut value)
+        .map_err(|error| incompatible_column_type::<T>(error, col_or_param_num))?;
+    Ok(value.into_opt())
+}
+
+/// Maps diagnostics indicating that the driver could not convert the value of the column into the
+/// requested C type to [`Error::IncompatibleColumnType`]. Other errors are passed through.
+fn incompatible_column_type<T>(error: Error, column: u16) -> Error {
+    match error {
+        Error::Diagnostics { record, function } => {
+            // SQLSTATE class `22` covers data exceptions like invalid casts, numeric values out of
+            // range or invalid datetime formats. `07006` is reported if the driver does not
+            // support the conversion at all.
+            if record.state.0.starts_with(b"22")
+                || record.state == State::RESTRICTED_DATA_TYPE_ATTRIBUTE_VIOLATION
+            {
+                Error::IncompatibleColumnType {
+                    column,
+                    target_type: type_name::<T>(),
+                    reason: record.to_string(),
+                }
+            } else {
+                Error::Diagnostics { record, function }
+            }
+        }
+        other => other,
+    }
+}
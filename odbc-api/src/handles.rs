@@ -14,6 +14,7 @@ mod data_type;
 mod descriptor;
 mod diagnostics;
 mod environment;
+mod ffi;
 mod logging;
 mod sql_char;
 mod sql_result;
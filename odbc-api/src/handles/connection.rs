@@ -15,8 +15,17 @@ use odbc_sys::{
     CompletionType, ConnectionAttribute, DriverConnectOption, HDbc, HEnv, HStmt, HWnd, Handle,
     HandleType, InfoType, Pointer, SQLAllocHandle, SQLDisconnect, SQLEndTran, IS_UINTEGER,
 };
+
+#[cfg(feature = "odbc_version_3_80")]
+use odbc_sys::SQLCancelHandle;
 use std::{ffi::c_void, marker::PhantomData, mem::size_of, ptr::null_mut};
 
+#[cfg(feature = "narrow")]
+use super::ffi::SQLGetInfo as sql_get_info_raw;
+
+#[cfg(not(feature = "narrow"))]
+use super::ffi::SQLGetInfoW as sql_get_info_raw;
+
 #[cfg(feature = "narrow")]
 use odbc_sys::{
     SQLConnect as sql_connect, SQLDriverConnect as sql_driver_connect,
@@ -170,6 +179,31 @@ impl<'c> Connection<'c> {
         .into_sql_result("SQLDriverConnect")
     }
 
+    /// Enables or disables asynchronous execution of functions on the connection, like
+    /// `SQLDriverConnect`, `SQLEndTran` or `SQLDisconnect`. If enabled, these functions may return
+    /// [`SqlResult::StillExecuting`] and need to be called again until they complete. This is
+    /// equivalent to setting `SQL_ATTR_ASYNC_DBC_FUNCTIONS_ENABLE` in the bare C API. Requires
+    /// driver support.
+    pub fn set_async_dbc_functions_enable(&self, on: bool) -> SqlResult<()> {
+        unsafe {
+            sql_set_connect_attr(
+                self.handle,
+                ConnectionAttribute::AsyncDbcFunctionsEnable,
+                on as usize as Pointer,
+                0,
+            )
+            .into_sql_result("SQLSetConnectAttr")
+        }
+    }
+
+    /// Cancels an asynchronous function executing on the connection.
+    #[cfg(feature = "odbc_version_3_80")]
+    pub fn cancel(&self) -> SqlResult<()> {
+        unsafe {
+            SQLCancelHandle(HandleType::Dbc, self.as_handle()).into_sql_result("SQLCancelHandle")
+        }
+    }
+
     /// Disconnect from an ODBC data source.
     pub fn disconnect(&mut self) -> SqlResult<()> {
         unsafe { SQLDisconnect(self.handle).into_sql_result("SQLDisconnect") }
@@ -246,6 +280,39 @@ impl<'c> Connection<'c> {
         }
     }
 
+    /// Set the transaction isolation level of the connection. `level` is one of the `SQL_TXN_*`
+    /// bitmask values, e.g. `SQL_TXN_READ_COMMITTED` (`2`). This corresponds to the
+    /// `SQL_ATTR_TXN_ISOLATION` attribute. Must not be called while a transaction is open.
+    pub fn set_transaction_isolation(&self, level: u32) -> SqlResult<()> {
+        unsafe {
+            sql_set_connect_attr(
+                self.handle,
+                ConnectionAttribute::TxnIsolation,
+                level as Pointer,
+                0,
+            )
+            .into_sql_result("SQLSetConnectAttr")
+        }
+    }
+
+    /// Switch the connection between read-only (`true`) and read-write (`false`) access mode.
+    /// This corresponds to the `SQL_ATTR_ACCESS_MODE` attribute. Drivers use the access mode as a
+    /// hint to optimize transactions and are not required to prevent modifications in read-only
+    /// mode.
+    pub fn set_read_only(&self, read_only: bool) -> SqlResult<()> {
+        // SQL_MODE_READ_WRITE = 0, SQL_MODE_READ_ONLY = 1
+        let mode = read_only as u32;
+        unsafe {
+            sql_set_connect_attr(
+                self.handle,
+                ConnectionAttribute::AccessMode,
+                mode as Pointer,
+                0,
+            )
+            .into_sql_result("SQLSetConnectAttr")
+        }
+    }
+
     /// To commit a transaction in manual-commit mode.
     pub fn commit(&self) -> SqlResult<()> {
         unsafe {
@@ -265,15 +332,71 @@ impl<'c> Connection<'c> {
     /// Fetch the name of the database management system used by the connection and store it into
     /// the provided `buf`.
     pub fn fetch_database_management_system_name(&self, buf: &mut Vec<SqlChar>) -> SqlResult<()> {
+        self.fetch_info_string(InfoType::DbmsName as u16, buf)
+    }
+
+    fn info_u16(&self, info_type: InfoType) -> SqlResult<u16> {
+        unsafe {
+            let mut value = 0u16;
+            sql_get_info(
+                self.handle,
+                info_type,
+                &mut value as *mut u16 as Pointer,
+                // Buffer length should not be required in this case, according to the ODBC
+                // documentation at https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetinfo-function?view=sql-server-ver15#arguments
+                // However, in practice some drivers (such as Microsoft Access) require it to be
+                // specified explicitly here, otherwise they return an error without diagnostics.
+                size_of::<*mut u16>() as i16,
+                null_mut(),
+            )
+            .into_sql_result("SQLGetInfo")
+            .on_success(|| value)
+        }
+    }
+
+    fn info_u32(&self, info_type: InfoType) -> SqlResult<u32> {
+        self.numeric_info(info_type as u16)
+    }
+
+    /// Fetch a numeric (`SQLUINTEGER`) information about the driver or the data source.
+    /// `info_type` is the numeric value of the `SQL_*` information type passed to `SQLGetInfo`,
+    /// e.g. `SQL_SQL_CONFORMANCE` (`118`). It is not an [`InfoType`], since the latter does not
+    /// cover all the information types defined by the ODBC standard.
+    ///
+    /// It is the responsibility of the caller to only request information types which are
+    /// reported as `SQLUINTEGER` or bitmasks thereof.
+    pub fn numeric_info(&self, info_type: u16) -> SqlResult<u32> {
+        unsafe {
+            let mut value = 0u32;
+            sql_get_info_raw(
+                self.handle,
+                info_type,
+                &mut value as *mut u32 as Pointer,
+                // See comment in `info_u16`
+                size_of::<*mut u32>() as i16,
+                null_mut(),
+            )
+            .into_sql_result("SQLGetInfo")
+            .on_success(|| value)
+        }
+    }
+
+    /// Fetch a string valued information about the driver or the data source and store it into
+    /// the provided `buf`. `info_type` is the numeric value of the `SQL_*` information type passed
+    /// to `SQLGetInfo`, e.g. `SQL_DRIVER_NAME` (`6`).
+    ///
+    /// It is the responsibility of the caller to only request information types which are
+    /// reported as character strings.
+    pub fn fetch_info_string(&self, info_type: u16, buf: &mut Vec<SqlChar>) -> SqlResult<()> {
         // String length in bytes, not characters. Terminating zero is excluded.
         let mut string_length_in_bytes: i16 = 0;
         // Let's utilize all of `buf`s capacity.
         buf.resize(buf.capacity(), 0);
 
         unsafe {
-            let mut res = sql_get_info(
+            let mut res = sql_get_info_raw(
                 self.handle,
-                InfoType::DbmsName,
+                info_type,
                 mut_buf_ptr(buf) as Pointer,
                 binary_length(buf).try_into().unwrap(),
                 &mut string_length_in_bytes as *mut i16,
@@ -288,9 +411,9 @@ impl<'c> Connection<'c> {
             if is_truncated_bin(buf, string_length_in_bytes.try_into().unwrap()) {
                 // It seems we must try again with a large enough buffer.
                 resize_to_fit_with_tz(buf, string_length_in_bytes.try_into().unwrap());
-                res = sql_get_info(
+                res = sql_get_info_raw(
                     self.handle,
-                    InfoType::DbmsName,
+                    info_type,
                     mut_buf_ptr(buf) as Pointer,
                     binary_length(buf).try_into().unwrap(),
                     &mut string_length_in_bytes as *mut i16,
@@ -308,23 +431,10 @@ impl<'c> Connection<'c> {
         }
     }
 
-    fn info_u16(&self, info_type: InfoType) -> SqlResult<u16> {
-        unsafe {
-            let mut value = 0u16;
-            sql_get_info(
-                self.handle,
-                info_type,
-                &mut value as *mut u16 as Pointer,
-                // Buffer length should not be required in this case, according to the ODBC
-                // documentation at https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlgetinfo-function?view=sql-server-ver15#arguments
-                // However, in practice some drivers (such as Microsoft Access) require it to be
-                // specified explicitly here, otherwise they return an error without diagnostics.
-                size_of::<*mut u16>() as i16,
-                null_mut(),
-            )
-            .into_sql_result("SQLGetInfo")
-            .on_success(|| value)
-        }
+    /// Bitmask of the transaction isolation levels supported by the data source. Corresponds to
+    /// the `SQL_TXN_ISOLATION_OPTION` information type.
+    pub fn transaction_isolation_options(&self) -> SqlResult<u32> {
+        self.info_u32(InfoType::TransactionIsolationProtocol)
     }
 
     /// Maximum length of catalog names.
@@ -347,6 +457,12 @@ impl<'c> Connection<'c> {
         self.info_u16(InfoType::MaxColumnNameLen)
     }
 
+    /// Level of transaction support offered by the driver. `0` (`SQL_TC_NONE`) indicates that
+    /// transactions are not supported at all.
+    pub fn transaction_capable(&self) -> SqlResult<u16> {
+        self.info_u16(InfoType::TransactionCapable)
+    }
+
     /// Fetch the name of the current catalog being used by the connection and store it into the
     /// provided `buf`.
     pub fn fetch_current_catalog(&self, buffer: &mut Vec<SqlChar>) -> SqlResult<()> {
@@ -404,6 +520,27 @@ impl<'c> Connection<'c> {
         }
     }
 
+    /// `true` if the connection is in auto-commit mode, `false` if in manual-commit mode.
+    pub fn is_autocommit(&self) -> SqlResult<bool> {
+        unsafe {
+            self.attribute_u32(ConnectionAttribute::AutoCommit)
+                .map(|v| v != 0)
+        }
+    }
+
+    /// Transaction isolation level of the connection as one of the `SQL_TXN_*` bitmask values.
+    pub fn transaction_isolation(&self) -> SqlResult<u32> {
+        unsafe { self.attribute_u32(ConnectionAttribute::TxnIsolation) }
+    }
+
+    /// `true` if the connection is in read-only access mode.
+    pub fn is_read_only(&self) -> SqlResult<bool> {
+        unsafe {
+            self.attribute_u32(ConnectionAttribute::AccessMode)
+                .map(|v| v != 0)
+        }
+    }
+
     /// Networ packet size in bytes.
     pub fn packet_size(&self) -> SqlResult<u32> {
         unsafe { self.attribute_u32(ConnectionAttribute::PacketSize) }
@@ -11,6 +11,7 @@ use odbc_sys::SqlDataType;
 /// Microsoft SQL Server return a custom type, with its meaning specific to that driver. PostgreSQL
 /// identifies that column as an ordinary ODBC timestamp.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Enumeration over valid SQL Data Types supported by ODBC
 pub enum DataType {
     /// The type is not known.
@@ -126,6 +127,7 @@ pub enum DataType {
     /// non-standard types.
     Other {
         /// Type of the column
+        #[cfg_attr(feature = "serde", serde(with = "serde_sql_data_type"))]
         data_type: SqlDataType,
         /// Size of column element
         column_size: Option<NonZeroUsize>,
@@ -411,3 +413,24 @@ impl DataType {
         }
     }
 }
+
+/// `odbc_sys::SqlDataType` does not implement the `serde` traits. It is represented by its numeric
+/// value instead.
+#[cfg(feature = "serde")]
+mod serde_sql_data_type {
+    use odbc_sys::SqlDataType;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        data_type: &SqlDataType,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i16(data_type.0)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<SqlDataType, D::Error> {
+        i16::deserialize(deserializer).map(SqlDataType)
+    }
+}
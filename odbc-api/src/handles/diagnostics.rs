@@ -5,8 +5,8 @@ use super::{
     buffer::{clamp_small_int, mut_buf_ptr},
     SqlChar,
 };
-use odbc_sys::{SqlReturn, SQLSTATE_SIZE};
-use std::fmt;
+use odbc_sys::{Pointer, SqlReturn, SQLSTATE_SIZE};
+use std::{fmt, ptr::null_mut};
 
 // Starting with odbc 5 we may be able to specify utf8 encoding. Until then, we may need to fall
 // back on the 'W' wide function calls.
@@ -16,6 +16,15 @@ use odbc_sys::SQLGetDiagRecW as sql_get_diag_rec;
 #[cfg(feature = "narrow")]
 use odbc_sys::SQLGetDiagRec as sql_get_diag_rec;
 
+#[cfg(not(feature = "narrow"))]
+use odbc_sys::SQLGetDiagFieldW as sql_get_diag_field;
+
+#[cfg(feature = "narrow")]
+use super::ffi::SQLGetDiagField as sql_get_diag_field;
+
+/// `SQL_DIAG_ROW_NUMBER` field identifier of a diagnostic record.
+const DIAG_ROW_NUMBER: i16 = -1248;
+
 /// A buffer large enough to hold an `SOLState` for diagnostics
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State(pub [u8; SQLSTATE_SIZE]);
@@ -33,6 +42,20 @@ impl State {
     pub const STRING_DATA_RIGHT_TRUNCATION: State = State(*b"01004");
     /// StrLen_or_IndPtr was a null pointer and NULL data was retrieved.
     pub const INDICATOR_VARIABLE_REQUIRED_BUT_NOT_SUPPLIED: State = State(*b"22002");
+    /// The query timeout period expired before the data source returned the result set. See
+    /// `SQL_ATTR_QUERY_TIMEOUT`.
+    pub const TIMEOUT_EXPIRED: State = State(*b"HYT00");
+    /// The function has been canceled using `SQLCancel` or `SQLCancelHandle`.
+    pub const OPERATION_CANCELED: State = State(*b"HY008");
+    /// The data value of a column in the result set cannot be converted to the C data type it is
+    /// fetched into.
+    pub const RESTRICTED_DATA_TYPE_ATTRIBUTE_VIOLATION: State = State(*b"07006");
+    /// The information type passed to `SQLGetInfo` is not valid for the version of ODBC supported
+    /// by the driver.
+    pub const INVALID_INFO_TYPE: State = State(*b"HY096");
+    /// The driver or data source does not support an optional feature, e.g. an information type
+    /// passed to `SQLGetInfo`.
+    pub const OPTIONAL_FEATURE_NOT_IMPLEMENTED: State = State(*b"HYC00");
 
     /// Drops terminating zero and changes char type, if required
     pub fn from_chars_with_nul(code: &[SqlChar; SQLSTATE_SIZE + 1]) -> Self {
@@ -166,6 +189,16 @@ pub trait Diagnostics {
                 result
             })
     }
+
+    /// One based number of the row in the row set, or of the parameter set in an array of
+    /// parameters, the diagnostic record `rec_number` is associated with. `None` if the record is
+    /// not associated with any row, the row number is unknown, or if the diagnostic record does not
+    /// exist.
+    ///
+    /// This corresponds to the `SQL_DIAG_ROW_NUMBER` field of a diagnostic record.
+    fn diagnostic_row_number(&self, _rec_number: i16) -> Option<usize> {
+        None
+    }
 }
 
 impl<T: AsHandle + ?Sized> Diagnostics for T {
@@ -208,6 +241,27 @@ impl<T: AsHandle + ?Sized> Diagnostics for T {
             unexpected => panic!("SQLGetDiagRec returned: {unexpected:?}"),
         }
     }
+
+    fn diagnostic_row_number(&self, rec_number: i16) -> Option<usize> {
+        assert!(rec_number > 0);
+        let mut row_number: isize = 0;
+        let ret = unsafe {
+            sql_get_diag_field(
+                self.handle_type(),
+                self.as_handle(),
+                rec_number,
+                DIAG_ROW_NUMBER,
+                &mut row_number as *mut isize as Pointer,
+                0,
+                null_mut(),
+            )
+        };
+        match ret {
+            // `SQL_NO_ROW_NUMBER` (-1) and `SQL_ROW_NUMBER_UNKNOWN` (-2) are mapped to `None`.
+            SqlReturn::SUCCESS | SqlReturn::SUCCESS_WITH_INFO => row_number.try_into().ok(),
+            _ => None,
+        }
+    }
 }
 
 /// ODBC Diagnostic Record
@@ -0,0 +1,234 @@
+//! Declarations of ODBC functions which are not (yet) exposed by `odbc-sys`, or which we need to
+//! call with a signature more permissive than the one offered by `odbc-sys`. The symbols are
+//! provided by the driver manager library `odbc-sys` already links against.
+
+use odbc_sys::{HDbc, HStmt, Pointer, SmallInt, SqlReturn, USmallInt};
+
+#[cfg(not(feature = "narrow"))]
+use odbc_sys::{SqlDataType, WChar};
+
+#[cfg(feature = "narrow")]
+use odbc_sys::{Handle, HandleType};
+
+// static linking is not currently supported here for windows
+#[cfg_attr(windows, link(name = "odbc32"))]
+extern "system" {
+    /// Same as `odbc_sys::SQLGetInfo`, yet takes the information type as a plain integer, so we
+    /// can request information types not covered by `odbc_sys::InfoType`.
+    #[cfg(feature = "narrow")]
+    pub fn SQLGetInfo(
+        connection_handle: HDbc,
+        info_type: u16,
+        info_value_ptr: Pointer,
+        buffer_length: SmallInt,
+        string_length_ptr: *mut SmallInt,
+    ) -> SqlReturn;
+
+    /// Same as `odbc_sys::SQLGetInfoW`, yet takes the information type as a plain integer, so we
+    /// can request information types not covered by `odbc_sys::InfoType`.
+    #[cfg(not(feature = "narrow"))]
+    pub fn SQLGetInfoW(
+        connection_handle: HDbc,
+        info_type: u16,
+        info_value_ptr: Pointer,
+        buffer_length: SmallInt,
+        string_length_ptr: *mut SmallInt,
+    ) -> SqlReturn;
+
+    /// Returns the column names that make up the primary key for a table as a result set.
+    #[cfg(feature = "narrow")]
+    pub fn SQLPrimaryKeys(
+        statement_handle: HStmt,
+        catalog_name: *const u8,
+        catalog_name_length: SmallInt,
+        schema_name: *const u8,
+        schema_name_length: SmallInt,
+        table_name: *const u8,
+        table_name_length: SmallInt,
+    ) -> SqlReturn;
+
+    /// Returns the column names that make up the primary key for a table as a result set.
+    #[cfg(not(feature = "narrow"))]
+    pub fn SQLPrimaryKeysW(
+        statement_handle: HStmt,
+        catalog_name: *const WChar,
+        catalog_name_length: SmallInt,
+        schema_name: *const WChar,
+        schema_name_length: SmallInt,
+        table_name: *const WChar,
+        table_name_length: SmallInt,
+    ) -> SqlReturn;
+
+    /// Retrieves a list of statistics about a single table and the indexes associated with the
+    /// table as a result set.
+    #[cfg(feature = "narrow")]
+    pub fn SQLStatistics(
+        statement_handle: HStmt,
+        catalog_name: *const u8,
+        catalog_name_length: SmallInt,
+        schema_name: *const u8,
+        schema_name_length: SmallInt,
+        table_name: *const u8,
+        table_name_length: SmallInt,
+        unique: USmallInt,
+        reserved: USmallInt,
+    ) -> SqlReturn;
+
+    /// Retrieves a list of statistics about a single table and the indexes associated with the
+    /// table as a result set.
+    #[cfg(not(feature = "narrow"))]
+    pub fn SQLStatisticsW(
+        statement_handle: HStmt,
+        catalog_name: *const WChar,
+        catalog_name_length: SmallInt,
+        schema_name: *const WChar,
+        schema_name_length: SmallInt,
+        table_name: *const WChar,
+        table_name_length: SmallInt,
+        unique: USmallInt,
+        reserved: USmallInt,
+    ) -> SqlReturn;
+
+    /// Returns the list of procedure names stored in a specific data source as a result set.
+    #[cfg(feature = "narrow")]
+    pub fn SQLProcedures(
+        statement_handle: HStmt,
+        catalog_name: *const u8,
+        catalog_name_length: SmallInt,
+        schema_name: *const u8,
+        schema_name_length: SmallInt,
+        proc_name: *const u8,
+        proc_name_length: SmallInt,
+    ) -> SqlReturn;
+
+    /// Returns the list of procedure names stored in a specific data source as a result set.
+    #[cfg(not(feature = "narrow"))]
+    pub fn SQLProceduresW(
+        statement_handle: HStmt,
+        catalog_name: *const WChar,
+        catalog_name_length: SmallInt,
+        schema_name: *const WChar,
+        schema_name_length: SmallInt,
+        proc_name: *const WChar,
+        proc_name_length: SmallInt,
+    ) -> SqlReturn;
+
+    /// Returns the list of input and output parameters, as well as the columns that make up the
+    /// result set for the specified procedures as a result set.
+    #[cfg(feature = "narrow")]
+    pub fn SQLProcedureColumns(
+        statement_handle: HStmt,
+        catalog_name: *const u8,
+        catalog_name_length: SmallInt,
+        schema_name: *const u8,
+        schema_name_length: SmallInt,
+        proc_name: *const u8,
+        proc_name_length: SmallInt,
+        column_name: *const u8,
+        column_name_length: SmallInt,
+    ) -> SqlReturn;
+
+    /// Returns the list of input and output parameters, as well as the columns that make up the
+    /// result set for the specified procedures as a result set.
+    #[cfg(not(feature = "narrow"))]
+    pub fn SQLProcedureColumnsW(
+        statement_handle: HStmt,
+        catalog_name: *const WChar,
+        catalog_name_length: SmallInt,
+        schema_name: *const WChar,
+        schema_name_length: SmallInt,
+        proc_name: *const WChar,
+        proc_name_length: SmallInt,
+        column_name: *const WChar,
+        column_name_length: SmallInt,
+    ) -> SqlReturn;
+
+    /// Retrieves information about the optimal set of columns that uniquely identifies a row in
+    /// the table, or the columns automatically updated when any value in the row is updated.
+    #[cfg(feature = "narrow")]
+    pub fn SQLSpecialColumns(
+        statement_handle: HStmt,
+        identifier_type: USmallInt,
+        catalog_name: *const u8,
+        catalog_name_length: SmallInt,
+        schema_name: *const u8,
+        schema_name_length: SmallInt,
+        table_name: *const u8,
+        table_name_length: SmallInt,
+        scope: USmallInt,
+        nullable: USmallInt,
+    ) -> SqlReturn;
+
+    /// Retrieves information about the optimal set of columns that uniquely identifies a row in
+    /// the table, or the columns automatically updated when any value in the row is updated.
+    #[cfg(not(feature = "narrow"))]
+    pub fn SQLSpecialColumnsW(
+        statement_handle: HStmt,
+        identifier_type: USmallInt,
+        catalog_name: *const WChar,
+        catalog_name_length: SmallInt,
+        schema_name: *const WChar,
+        schema_name_length: SmallInt,
+        table_name: *const WChar,
+        table_name_length: SmallInt,
+        scope: USmallInt,
+        nullable: USmallInt,
+    ) -> SqlReturn;
+
+    /// Returns a list of tables and the privileges associated with each table as a result set.
+    #[cfg(feature = "narrow")]
+    pub fn SQLTablePrivileges(
+        statement_handle: HStmt,
+        catalog_name: *const u8,
+        catalog_name_length: SmallInt,
+        schema_name: *const u8,
+        schema_name_length: SmallInt,
+        table_name: *const u8,
+        table_name_length: SmallInt,
+    ) -> SqlReturn;
+
+    /// Returns a list of tables and the privileges associated with each table as a result set.
+    #[cfg(not(feature = "narrow"))]
+    pub fn SQLTablePrivilegesW(
+        statement_handle: HStmt,
+        catalog_name: *const WChar,
+        catalog_name_length: SmallInt,
+        schema_name: *const WChar,
+        schema_name_length: SmallInt,
+        table_name: *const WChar,
+        table_name_length: SmallInt,
+    ) -> SqlReturn;
+
+    /// Returns a list of columns and associated privileges for the specified table as a result
+    /// set. The wide version is provided by `odbc-sys`.
+    #[cfg(feature = "narrow")]
+    pub fn SQLColumnPrivileges(
+        statement_handle: HStmt,
+        catalog_name: *const u8,
+        catalog_name_length: SmallInt,
+        schema_name: *const u8,
+        schema_name_length: SmallInt,
+        table_name: *const u8,
+        table_name_length: SmallInt,
+        column_name: *const u8,
+        column_name_length: SmallInt,
+    ) -> SqlReturn;
+
+    /// Returns information about data types supported by the data source as a result set. The
+    /// narrow version is provided by `odbc-sys`.
+    #[cfg(not(feature = "narrow"))]
+    pub fn SQLGetTypeInfoW(statement_handle: HStmt, data_type: SqlDataType) -> SqlReturn;
+
+    /// Returns the current value of a field of a diagnostic record. The wide version is provided
+    /// by `odbc-sys`.
+    #[cfg(feature = "narrow")]
+    pub fn SQLGetDiagField(
+        handle_type: HandleType,
+        handle: Handle,
+        record_number: SmallInt,
+        diag_identifier: SmallInt,
+        diag_info_ptr: Pointer,
+        buffer_length: SmallInt,
+        string_length_ptr: *mut SmallInt,
+    ) -> SqlReturn;
+}
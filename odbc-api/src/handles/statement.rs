@@ -11,10 +11,11 @@ use super::{
 };
 use log::debug;
 use odbc_sys::{
-    Desc, FreeStmtOption, HDbc, HStmt, Handle, HandleType, Len, ParamType, Pointer, SQLBindCol,
-    SQLBindParameter, SQLCloseCursor, SQLDescribeParam, SQLExecute, SQLFetch, SQLFreeStmt,
+    BulkOperation, Desc, FetchOrientation, FreeStmtOption, HDbc, HStmt, Handle, HandleType, Len,
+    Lock, Operation, ParamType, Pointer, SQLBindCol, SQLBindParameter, SQLBulkOperations,
+    SQLCancel, SQLCloseCursor, SQLDescribeParam, SQLExecute, SQLFetch, SQLFetchScroll, SQLFreeStmt,
     SQLGetData, SQLMoreResults, SQLNumParams, SQLNumResultCols, SQLParamData, SQLPutData,
-    SQLRowCount, SqlDataType, SqlReturn, StatementAttribute, IS_POINTER,
+    SQLRowCount, SQLSetPos, SetPosIRow, SqlDataType, SqlReturn, StatementAttribute, IS_POINTER,
 };
 use std::{ffi::c_void, marker::PhantomData, mem::ManuallyDrop, num::NonZeroUsize, ptr::null_mut};
 
@@ -25,16 +26,32 @@ use odbc_sys::SQLCompleteAsync;
 use odbc_sys::{
     SQLColAttribute as sql_col_attribute, SQLColumns as sql_columns,
     SQLDescribeCol as sql_describe_col, SQLExecDirect as sql_exec_direc,
-    SQLForeignKeys as sql_foreign_keys, SQLPrepare as sql_prepare,
-    SQLSetStmtAttr as sql_set_stmt_attr, SQLTables as sql_tables,
+    SQLForeignKeys as sql_foreign_keys, SQLGetTypeInfo as sql_get_type_info,
+    SQLPrepare as sql_prepare, SQLSetStmtAttr as sql_set_stmt_attr, SQLTables as sql_tables,
+};
+
+#[cfg(feature = "narrow")]
+use super::ffi::{
+    SQLColumnPrivileges as sql_column_privileges, SQLPrimaryKeys as sql_primary_keys,
+    SQLProcedureColumns as sql_procedure_columns, SQLProcedures as sql_procedures,
+    SQLSpecialColumns as sql_special_columns, SQLStatistics as sql_statistics,
+    SQLTablePrivileges as sql_table_privileges,
+};
+
+#[cfg(not(feature = "narrow"))]
+use super::ffi::{
+    SQLGetTypeInfoW as sql_get_type_info, SQLPrimaryKeysW as sql_primary_keys,
+    SQLProcedureColumnsW as sql_procedure_columns, SQLProceduresW as sql_procedures,
+    SQLSpecialColumnsW as sql_special_columns, SQLStatisticsW as sql_statistics,
+    SQLTablePrivilegesW as sql_table_privileges,
 };
 
 #[cfg(not(feature = "narrow"))]
 use odbc_sys::{
-    SQLColAttributeW as sql_col_attribute, SQLColumnsW as sql_columns,
-    SQLDescribeColW as sql_describe_col, SQLExecDirectW as sql_exec_direc,
-    SQLForeignKeysW as sql_foreign_keys, SQLPrepareW as sql_prepare,
-    SQLSetStmtAttrW as sql_set_stmt_attr, SQLTablesW as sql_tables,
+    SQLColAttributeW as sql_col_attribute, SQLColumnPrivilegesW as sql_column_privileges,
+    SQLColumnsW as sql_columns, SQLDescribeColW as sql_describe_col,
+    SQLExecDirectW as sql_exec_direc, SQLForeignKeysW as sql_foreign_keys,
+    SQLPrepareW as sql_prepare, SQLSetStmtAttrW as sql_set_stmt_attr, SQLTablesW as sql_tables,
 };
 
 /// An owned valid (i.e. successfully allocated) ODBC statement handle.
@@ -206,6 +223,55 @@ pub trait Statement: AsHandle {
         SQLFetch(self.as_sys()).into_sql_result("SQLFetch")
     }
 
+    /// Fetches the specified rowset of data from the result set and returns data for all bound
+    /// columns. Rowsets can be specified at an absolute or relative position. Moving the cursor in
+    /// any other direction than [`FetchOrientation::Next`] requires a scrollable cursor. See
+    /// [`Self::set_cursor_type`] and [`Self::set_cursor_scrollable`].
+    ///
+    /// `offset` is the number of the row to fetch for [`FetchOrientation::Absolute`] and the
+    /// number of rows to move for [`FetchOrientation::Relative`]. It is ignored for all other
+    /// orientations.
+    ///
+    /// # Safety
+    ///
+    /// Fetch dereferences bound column pointers.
+    unsafe fn fetch_scroll(
+        &mut self,
+        orientation: FetchOrientation,
+        offset: isize,
+    ) -> SqlResult<()> {
+        SQLFetchScroll(self.as_sys(), orientation, offset).into_sql_result("SQLFetchScroll")
+    }
+
+    /// Sets the cursor position in a rowset and allows an application to refresh, update or delete
+    /// data in the rowset. `row_number` is the one based position of the row in the rowset the
+    /// `operation` is applied to. `0` applies the operation to every row in the rowset.
+    ///
+    /// # Safety
+    ///
+    /// Updating rows dereferences bound column pointers. Each bound buffer must be valid for every
+    /// row the operation is applied to.
+    unsafe fn set_pos(
+        &mut self,
+        row_number: usize,
+        operation: Operation,
+        lock: Lock,
+    ) -> SqlResult<()> {
+        SQLSetPos(self.as_sys(), row_number as SetPosIRow, operation, lock)
+            .into_sql_result("SQLSetPos")
+    }
+
+    /// Performs bulk insertions and bulk bookmark operations. For [`BulkOperation::Add`] the first
+    /// `SQL_ATTR_ROW_ARRAY_SIZE` rows of the bound buffers are inserted.
+    ///
+    /// # Safety
+    ///
+    /// Dereferences bound column pointers. Each bound buffer must be valid for
+    /// `SQL_ATTR_ROW_ARRAY_SIZE` rows.
+    unsafe fn bulk_operations(&mut self, operation: BulkOperation) -> SqlResult<()> {
+        SQLBulkOperations(self.as_sys(), operation).into_sql_result("SQLBulkOperations")
+    }
+
     /// Retrieves data for a single column in the result set or for a single parameter.
     fn get_data(&mut self, col_or_param_num: u16, target: &mut impl CDataMut) -> SqlResult<()> {
         unsafe {
@@ -259,6 +325,101 @@ pub trait Statement: AsHandle {
         }
     }
 
+    /// Bind an array to hold the status of each row retrieved with fetch in the current row set.
+    /// Calling [`Self::unset_row_status`] is going to unbind the array from the statement.
+    ///
+    /// # Safety
+    ///
+    /// `row_status` must not be moved and remain valid, as long as it remains bound to the cursor.
+    /// It must hold at least as many elements as the row array size.
+    unsafe fn set_row_status(&mut self, row_status: &mut [u16]) -> SqlResult<()> {
+        let value = row_status.as_mut_ptr() as Pointer;
+        sql_set_stmt_attr(
+            self.as_sys(),
+            StatementAttribute::RowStatusPtr,
+            value,
+            IS_POINTER,
+        )
+        .into_sql_result("SQLSetStmtAttr")
+    }
+
+    /// Unsets the array set by [`Self::set_row_status`].
+    fn unset_row_status(&mut self) -> SqlResult<()> {
+        unsafe {
+            sql_set_stmt_attr(
+                self.as_sys(),
+                StatementAttribute::RowStatusPtr,
+                null_mut(),
+                IS_POINTER,
+            )
+            .into_sql_result("SQLSetStmtAttr")
+        }
+    }
+
+    /// Bind an array to hold the status of each parameter set, then executing a statement with an
+    /// array of parameters. Calling [`Self::unset_param_status`] is going to unbind the array from
+    /// the statement.
+    ///
+    /// # Safety
+    ///
+    /// `param_status` must not be moved and remain valid, as long as it remains bound to the
+    /// statement. It must hold at least as many elements as the parameter set size.
+    unsafe fn set_param_status(&mut self, param_status: &mut [u16]) -> SqlResult<()> {
+        let value = param_status.as_mut_ptr() as Pointer;
+        sql_set_stmt_attr(
+            self.as_sys(),
+            StatementAttribute::ParamStatusPtr,
+            value,
+            IS_POINTER,
+        )
+        .into_sql_result("SQLSetStmtAttr")
+    }
+
+    /// Unsets the array set by [`Self::set_param_status`].
+    fn unset_param_status(&mut self) -> SqlResult<()> {
+        unsafe {
+            sql_set_stmt_attr(
+                self.as_sys(),
+                StatementAttribute::ParamStatusPtr,
+                null_mut(),
+                IS_POINTER,
+            )
+            .into_sql_result("SQLSetStmtAttr")
+        }
+    }
+
+    /// Bind an integer to hold the number of parameter sets processed, then executing a statement
+    /// with an array of parameters. Calling [`Self::unset_params_processed`] is going to unbind
+    /// the value from the statement.
+    ///
+    /// # Safety
+    ///
+    /// `params_processed` must not be moved and remain valid, as long as it remains bound to the
+    /// statement.
+    unsafe fn set_params_processed(&mut self, params_processed: &mut usize) -> SqlResult<()> {
+        let value = params_processed as *mut usize as Pointer;
+        sql_set_stmt_attr(
+            self.as_sys(),
+            StatementAttribute::ParamsProcessedPtr,
+            value,
+            IS_POINTER,
+        )
+        .into_sql_result("SQLSetStmtAttr")
+    }
+
+    /// Unsets the integer set by [`Self::set_params_processed`].
+    fn unset_params_processed(&mut self) -> SqlResult<()> {
+        unsafe {
+            sql_set_stmt_attr(
+                self.as_sys(),
+                StatementAttribute::ParamsProcessedPtr,
+                null_mut(),
+                IS_POINTER,
+            )
+            .into_sql_result("SQLSetStmtAttr")
+        }
+    }
+
     /// Fetch a column description using the column index.
     ///
     /// # Parameters
@@ -344,6 +505,14 @@ pub trait Statement: AsHandle {
         unsafe { SQLCloseCursor(self.as_sys()) }.into_sql_result("SQLCloseCursor")
     }
 
+    /// Cancel the processing of the statement. Can be called from a different thread than the one
+    /// the statement is executed or fetched on, in order to abort a blocking function call. The
+    /// canceled function returns with SQLSTATE `HY008` (Operation canceled). If no function is
+    /// currently processing the statement, this has no effect.
+    fn cancel(&self) -> SqlResult<()> {
+        unsafe { SQLCancel(self.as_sys()) }.into_sql_result("SQLCancel")
+    }
+
     /// Send an SQL statement to the data source for preparation. The application can include one or
     /// more parameter markers in the SQL statement. To include a parameter marker, the application
     /// embeds a question mark (?) into the SQL string at the appropriate position.
@@ -451,6 +620,25 @@ pub trait Statement: AsHandle {
         .into_sql_result("SQLSetStmtAttr")
     }
 
+    /// Sets the binding type of parameter arrays.
+    ///
+    /// Any Positive number indicates a row wise binding with that row length. `0` indicates a
+    /// columnar binding.
+    ///
+    /// # Safety
+    ///
+    /// It is the callers responsibility to ensure that the bound parameters match the memory layout
+    /// specified by this function.
+    unsafe fn set_param_bind_type(&mut self, row_size: usize) -> SqlResult<()> {
+        sql_set_stmt_attr(
+            self.as_sys(),
+            StatementAttribute::ParamBindType,
+            row_size as Pointer,
+            0,
+        )
+        .into_sql_result("SQLSetStmtAttr")
+    }
+
     fn set_metadata_id(&mut self, metadata_id: bool) -> SqlResult<()> {
         unsafe {
             sql_set_stmt_attr(
@@ -483,6 +671,85 @@ pub trait Statement: AsHandle {
         }
     }
 
+    /// Number of seconds to wait for an SQL statement to execute before returning to the
+    /// application. `0` means there is no timeout. This is equivalent to setting
+    /// `SQL_ATTR_QUERY_TIMEOUT` in the bare C API.
+    fn set_query_timeout_sec(&mut self, timeout_sec: usize) -> SqlResult<()> {
+        unsafe {
+            sql_set_stmt_attr(
+                self.as_sys(),
+                StatementAttribute::QueryTimeout,
+                timeout_sec as Pointer,
+                0,
+            )
+            .into_sql_result("SQLSetStmtAttr")
+        }
+    }
+
+    /// Maximum number of rows to return to the application for a `SELECT` statement. `0` means
+    /// all rows are returned. This is equivalent to setting `SQL_ATTR_MAX_ROWS` in the bare C API.
+    fn set_max_rows(&mut self, max_rows: usize) -> SqlResult<()> {
+        unsafe {
+            sql_set_stmt_attr(
+                self.as_sys(),
+                StatementAttribute::MaxRows,
+                max_rows as Pointer,
+                0,
+            )
+            .into_sql_result("SQLSetStmtAttr")
+        }
+    }
+
+    /// Type of cursor opened by executing a query. One of `SQL_CURSOR_FORWARD_ONLY` (`0`),
+    /// `SQL_CURSOR_KEYSET_DRIVEN` (`1`), `SQL_CURSOR_DYNAMIC` (`2`) or `SQL_CURSOR_STATIC` (`3`).
+    /// This is equivalent to setting `SQL_ATTR_CURSOR_TYPE` in the bare C API. Must be set before
+    /// the statement is prepared or executed.
+    fn set_cursor_type(&mut self, cursor_type: usize) -> SqlResult<()> {
+        unsafe {
+            sql_set_stmt_attr(
+                self.as_sys(),
+                StatementAttribute::CursorType,
+                cursor_type as Pointer,
+                0,
+            )
+            .into_sql_result("SQLSetStmtAttr")
+        }
+    }
+
+    /// `true` requires cursors opened by executing a query to be scrollable. The driver is free to
+    /// choose a cursor type supporting this. This is equivalent to setting
+    /// `SQL_ATTR_CURSOR_SCROLLABLE` in the bare C API. Must be set before the statement is prepared
+    /// or executed.
+    fn set_cursor_scrollable(&mut self, scrollable: bool) -> SqlResult<()> {
+        // SQL_NONSCROLLABLE = 0, SQL_SCROLLABLE = 1
+        let value: usize = if scrollable { 1 } else { 0 };
+        unsafe {
+            sql_set_stmt_attr(
+                self.as_sys(),
+                StatementAttribute::CursorScrollable,
+                value as Pointer,
+                0,
+            )
+            .into_sql_result("SQLSetStmtAttr")
+        }
+    }
+
+    /// Concurrency control used by cursors opened by executing a query. One of
+    /// `SQL_CONCUR_READ_ONLY` (`1`), `SQL_CONCUR_LOCK` (`2`), `SQL_CONCUR_ROWVER` (`3`) or
+    /// `SQL_CONCUR_VALUES` (`4`). This is equivalent to setting `SQL_ATTR_CONCURRENCY` in the bare C
+    /// API. Must be set before the statement is prepared or executed.
+    fn set_concurrency(&mut self, concurrency: usize) -> SqlResult<()> {
+        unsafe {
+            sql_set_stmt_attr(
+                self.as_sys(),
+                StatementAttribute::Concurrency,
+                concurrency as Pointer,
+                0,
+            )
+            .into_sql_result("SQLSetStmtAttr")
+        }
+    }
+
     /// Binds a buffer holding an input parameter to a parameter marker in an SQL statement. This
     /// specialized version takes a constant reference to parameter, but is therefore limited to
     /// binding input parameters. See [`Statement::bind_parameter`] for the version which can bind
@@ -876,6 +1143,229 @@ pub trait Statement: AsHandle {
         }
     }
 
+    /// Returns the column names that make up the primary key for a table. The driver returns the
+    /// information as a result set.
+    ///
+    /// Like [`Self::tables`] this changes the statement to a cursor over the result set.
+    fn primary_keys(
+        &mut self,
+        catalog_name: &SqlText,
+        schema_name: &SqlText,
+        table_name: &SqlText,
+    ) -> SqlResult<()> {
+        unsafe {
+            sql_primary_keys(
+                self.as_sys(),
+                catalog_name.ptr(),
+                catalog_name.len_char().try_into().unwrap(),
+                schema_name.ptr(),
+                schema_name.len_char().try_into().unwrap(),
+                table_name.ptr(),
+                table_name.len_char().try_into().unwrap(),
+            )
+            .into_sql_result("SQLPrimaryKeys")
+        }
+    }
+
+    /// Retrieves a list of statistics about a single table and the indexes associated with the
+    /// table. The driver returns the information as a result set.
+    ///
+    /// * `unique_only`: If `true` only unique indexes are returned (`SQL_INDEX_UNIQUE`), otherwise
+    ///   all indexes (`SQL_INDEX_ALL`).
+    /// * `ensure_accuracy`: If `true` the driver is requested to retrieve `CARDINALITY` and `PAGES`
+    ///   unconditionally (`SQL_ENSURE`), otherwise only if they are readily available
+    ///   (`SQL_QUICK`).
+    ///
+    /// Like [`Self::tables`] this changes the statement to a cursor over the result set.
+    fn statistics(
+        &mut self,
+        catalog_name: &SqlText,
+        schema_name: &SqlText,
+        table_name: &SqlText,
+        unique_only: bool,
+        ensure_accuracy: bool,
+    ) -> SqlResult<()> {
+        // SQL_INDEX_UNIQUE = 0, SQL_INDEX_ALL = 1
+        let unique = if unique_only { 0 } else { 1 };
+        // SQL_QUICK = 0, SQL_ENSURE = 1
+        let reserved = if ensure_accuracy { 1 } else { 0 };
+        unsafe {
+            sql_statistics(
+                self.as_sys(),
+                catalog_name.ptr(),
+                catalog_name.len_char().try_into().unwrap(),
+                schema_name.ptr(),
+                schema_name.len_char().try_into().unwrap(),
+                table_name.ptr(),
+                table_name.len_char().try_into().unwrap(),
+                unique,
+                reserved,
+            )
+            .into_sql_result("SQLStatistics")
+        }
+    }
+
+    /// Returns the list of procedure names stored in a specific data source. The driver returns
+    /// the information as a result set.
+    ///
+    /// The schema and procedure name parameters are search patterns by default unless
+    /// [`Self::set_metadata_id`] is called with `true`.
+    ///
+    /// Like [`Self::tables`] this changes the statement to a cursor over the result set.
+    fn procedures(
+        &mut self,
+        catalog_name: &SqlText,
+        schema_name: &SqlText,
+        procedure_name: &SqlText,
+    ) -> SqlResult<()> {
+        unsafe {
+            sql_procedures(
+                self.as_sys(),
+                catalog_name.ptr(),
+                catalog_name.len_char().try_into().unwrap(),
+                schema_name.ptr(),
+                schema_name.len_char().try_into().unwrap(),
+                procedure_name.ptr(),
+                procedure_name.len_char().try_into().unwrap(),
+            )
+            .into_sql_result("SQLProcedures")
+        }
+    }
+
+    /// Returns the list of input and output parameters, as well as the columns that make up the
+    /// result set for the specified procedures. The driver returns the information as a result
+    /// set.
+    ///
+    /// The schema, procedure and column name parameters are search patterns by default unless
+    /// [`Self::set_metadata_id`] is called with `true`.
+    ///
+    /// Like [`Self::tables`] this changes the statement to a cursor over the result set.
+    fn procedure_columns(
+        &mut self,
+        catalog_name: &SqlText,
+        schema_name: &SqlText,
+        procedure_name: &SqlText,
+        column_name: &SqlText,
+    ) -> SqlResult<()> {
+        unsafe {
+            sql_procedure_columns(
+                self.as_sys(),
+                catalog_name.ptr(),
+                catalog_name.len_char().try_into().unwrap(),
+                schema_name.ptr(),
+                schema_name.len_char().try_into().unwrap(),
+                procedure_name.ptr(),
+                procedure_name.len_char().try_into().unwrap(),
+                column_name.ptr(),
+                column_name.len_char().try_into().unwrap(),
+            )
+            .into_sql_result("SQLProcedureColumns")
+        }
+    }
+
+    /// Retrieves either the optimal set of columns that uniquely identifies a row in the table, or
+    /// the columns that are automatically updated when any value in the row is updated by a
+    /// transaction. The driver returns the information as a result set.
+    ///
+    /// * `identifier_type`: `SQL_BEST_ROWID` (`1`) or `SQL_ROWVER` (`2`).
+    /// * `scope`: Minimum required scope of the row id. `SQL_SCOPE_CURROW` (`0`),
+    ///   `SQL_SCOPE_TRANSACTION` (`1`) or `SQL_SCOPE_SESSION` (`2`).
+    /// * `nullable`: If `true` (`SQL_NULLABLE`) special columns which can have `NULL` values are
+    ///   returned, otherwise (`SQL_NO_NULLS`) they are excluded.
+    ///
+    /// Like [`Self::tables`] this changes the statement to a cursor over the result set.
+    fn special_columns(
+        &mut self,
+        identifier_type: u16,
+        catalog_name: &SqlText,
+        schema_name: &SqlText,
+        table_name: &SqlText,
+        scope: u16,
+        nullable: bool,
+    ) -> SqlResult<()> {
+        unsafe {
+            sql_special_columns(
+                self.as_sys(),
+                identifier_type,
+                catalog_name.ptr(),
+                catalog_name.len_char().try_into().unwrap(),
+                schema_name.ptr(),
+                schema_name.len_char().try_into().unwrap(),
+                table_name.ptr(),
+                table_name.len_char().try_into().unwrap(),
+                scope,
+                nullable.into(),
+            )
+            .into_sql_result("SQLSpecialColumns")
+        }
+    }
+
+    /// Returns a list of tables and the privileges associated with each table. The driver returns
+    /// the information as a result set.
+    ///
+    /// The schema and table name parameters are search patterns by default unless
+    /// [`Self::set_metadata_id`] is called with `true`.
+    ///
+    /// Like [`Self::tables`] this changes the statement to a cursor over the result set.
+    fn table_privileges(
+        &mut self,
+        catalog_name: &SqlText,
+        schema_name: &SqlText,
+        table_name: &SqlText,
+    ) -> SqlResult<()> {
+        unsafe {
+            sql_table_privileges(
+                self.as_sys(),
+                catalog_name.ptr(),
+                catalog_name.len_char().try_into().unwrap(),
+                schema_name.ptr(),
+                schema_name.len_char().try_into().unwrap(),
+                table_name.ptr(),
+                table_name.len_char().try_into().unwrap(),
+            )
+            .into_sql_result("SQLTablePrivileges")
+        }
+    }
+
+    /// Returns a list of columns and associated privileges for the specified table. The driver
+    /// returns the information as a result set.
+    ///
+    /// The column name parameter is a search pattern by default unless [`Self::set_metadata_id`]
+    /// is called with `true`.
+    ///
+    /// Like [`Self::tables`] this changes the statement to a cursor over the result set.
+    fn column_privileges(
+        &mut self,
+        catalog_name: &SqlText,
+        schema_name: &SqlText,
+        table_name: &SqlText,
+        column_name: &SqlText,
+    ) -> SqlResult<()> {
+        unsafe {
+            sql_column_privileges(
+                self.as_sys(),
+                catalog_name.ptr(),
+                catalog_name.len_char().try_into().unwrap(),
+                schema_name.ptr(),
+                schema_name.len_char().try_into().unwrap(),
+                table_name.ptr(),
+                table_name.len_char().try_into().unwrap(),
+                column_name.ptr(),
+                column_name.len_char().try_into().unwrap(),
+            )
+            .into_sql_result("SQLColumnPrivileges")
+        }
+    }
+
+    /// Returns information about the data types supported by the data source. The driver returns
+    /// the information as a result set. Pass [`SqlDataType::UNKNOWN_TYPE`] (`SQL_ALL_TYPES`) to
+    /// retrieve information about all data types.
+    ///
+    /// Like [`Self::tables`] this changes the statement to a cursor over the result set.
+    fn type_info(&mut self, data_type: SqlDataType) -> SqlResult<()> {
+        unsafe { sql_get_type_info(self.as_sys(), data_type).into_sql_result("SQLGetTypeInfo") }
+    }
+
     /// To put a batch of binary data into the data source at statement execution time. May return
     /// [`SqlResult::NeedData`]
     ///
@@ -4,9 +4,15 @@
 //! standard to access databases. See the [`guide`] for more information and code
 //! examples.
 
+#[cfg(feature = "futures")]
+mod block_cursor_stream;
+mod cancel_handle;
+mod catalog;
 mod columnar_bulk_inserter;
 mod concurrent_block_cursor;
 mod connection;
+mod connection_info;
+mod connection_pool;
 mod conversion;
 mod cursor;
 mod driver_complete_option;
@@ -14,6 +20,8 @@ mod environment;
 mod error;
 mod execute;
 mod fixed_sized;
+mod from_row;
+mod from_sql;
 mod into_parameter;
 mod narrow;
 mod nullable;
@@ -23,6 +31,10 @@ mod prepared;
 mod result_set_metadata;
 mod sleep;
 mod statement_connection;
+mod statement_options;
+mod table_schema;
+mod transaction;
+mod type_info;
 
 pub mod buffers;
 pub mod guide;
@@ -30,18 +42,31 @@ pub mod handles;
 pub mod parameter;
 
 pub use self::{
-    columnar_bulk_inserter::{BoundInputSlice, ColumnarBulkInserter},
+    cancel_handle::CancelHandle,
+    catalog::{
+        CatalogIter, CatalogRecord, ColumnInfo, ForeignKeyInfo, ProcedureColumnRow,
+        ReferentialAction, RowIdScope, RowIdentifierType, StatisticsRow, TableInfo,
+    },
+    columnar_bulk_inserter::{
+        BatchReport, BoundInputSlice, ColumnarBulkInserter, FailedRow, ParamStatus,
+    },
     concurrent_block_cursor::ConcurrentBlockCursor,
-    connection::{escape_attribute_value, Connection, ConnectionOptions},
+    connection::{escape_attribute_value, Connection, ConnectionOptions, IsolationLevel},
+    connection_info::{
+        BatchSupport, ConnectionInfo, GetDataExtensions, ScalarFunctions, SqlConformance,
+    },
+    connection_pool::{ConnectionPool, PoolOptions, PooledConnection},
     conversion::decimal_text_to_i128,
     cursor::{
         BlockCursor, BlockCursorPolling, Cursor, CursorImpl, CursorPolling, CursorRow,
-        RowSetBuffer, TruncationInfo,
+        CursorRowPolling, LockType, RowSetBuffer, RowStatus, SetPosOperation, TruncationInfo,
     },
     driver_complete_option::DriverCompleteOption,
     environment::{DataSourceInfo, DriverInfo, Environment, environment},
     error::{Error, TooLargeBufferSize},
     fixed_sized::Bit,
+    from_row::{FromRow, FromRowCursor},
+    from_sql::FromSql,
     handles::{ColumnDescription, DataType, Nullability},
     into_parameter::IntoParameter,
     narrow::Narrow,
@@ -53,6 +78,10 @@ pub use self::{
     result_set_metadata::ResultSetMetadata,
     sleep::Sleep,
     statement_connection::StatementConnection,
+    statement_options::{Concurrency, CursorType, StatementOptions},
+    table_schema::{ForeignKey, Index, PrimaryKey, TableSchema},
+    transaction::{Savepoint, Transaction},
+    type_info::{Searchable, TypeInfo},
 };
 
 /// Reexports `odbc-sys` as sys to enable applications to always use the same version as this
@@ -60,6 +89,9 @@ pub use self::{
 pub use odbc_sys as sys;
 pub use widestring::{U16Str, U16String};
 
-// Reexport fetch if derive feature is enabled
+#[cfg(feature = "futures")]
+pub use self::block_cursor_stream::BlockCursorStream;
+
+// Reexport derive macros if derive feature is enabled
 #[cfg(feature = "derive")]
-pub use odbc_api_derive::Fetch;
+pub use odbc_api_derive::{Fetch, FromRow, Insert};
@@ -1,10 +1,13 @@
 use crate::{
     execute::{
-        execute_columns, execute_foreign_keys, execute_tables, execute_with_parameters,
+        execute_column_privileges, execute_columns, execute_foreign_keys, execute_primary_keys,
+        execute_procedure_columns, execute_procedures, execute_special_columns, execute_statistics,
+        execute_table_privileges, execute_tables, execute_with_parameters,
         execute_with_parameters_polling,
     },
     handles::{AsStatementRef, SqlText, Statement, StatementImpl, StatementRef},
-    CursorImpl, CursorPolling, Error, ParameterCollectionRef, Sleep,
+    CancelHandle, CatalogIter, ColumnInfo, CursorImpl, CursorPolling, Error, ForeignKeyInfo,
+    ParameterCollectionRef, RowIdScope, RowIdentifierType, Sleep, StatementOptions, TableInfo,
 };
 
 /// A preallocated SQL statement handle intended for sequential execution of different queries. See
@@ -100,6 +103,17 @@ impl<'o> Preallocated<'o> {
         execute_with_parameters(move || Ok(&mut self.statement), Some(&query), params)
     }
 
+    /// Apply `options` to the statement. The options stay in effect for all subsequent executions.
+    pub fn set_options(&mut self, options: StatementOptions) -> Result<(), Error> {
+        options.apply(&mut self.statement)
+    }
+
+    /// A handle which can be used to cancel the execution of this statement from another thread.
+    /// See [`CancelHandle`].
+    pub fn cancel_handle(&mut self) -> CancelHandle {
+        CancelHandle::new(self.statement.as_sys())
+    }
+
     /// Transfer ownership to the underlying statement handle.
     ///
     /// The resulting type is one level of indirection away from the raw pointer of the ODBC API. It
@@ -190,6 +204,248 @@ impl<'o> Preallocated<'o> {
         )
     }
 
+    /// Retrieves the optimal set of columns that uniquely identifies a row in the table
+    /// ([`RowIdentifierType::BestRowId`]), or the columns that are automatically updated when any
+    /// value in the row is updated by a transaction ([`RowIdentifierType::RowVersion`]). The
+    /// returned cursor has the columns: `SCOPE`, `COLUMN_NAME`, `DATA_TYPE`, `TYPE_NAME`,
+    /// `COLUMN_SIZE`, `BUFFER_LENGTH`, `DECIMAL_DIGITS`, `PSEUDO_COLUMN`.
+    ///
+    /// The arguments are not search patterns. Empty strings are passed as `NULL` to the driver, i.e.
+    /// an empty `catalog_name` or `schema_name` does not restrict the result. `table_name` must not
+    /// be empty.
+    ///
+    /// * `scope`: Minimum required scope of the row identifier.
+    /// * `nullable`: If `true` special columns which can have `NULL` values are returned, otherwise
+    ///   they are excluded.
+    ///
+    /// See: <https://learn.microsoft.com/sql/odbc/reference/syntax/sqlspecialcolumns-function>
+    pub fn special_columns(
+        &mut self,
+        identifier_type: RowIdentifierType,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+        scope: RowIdScope,
+        nullable: bool,
+    ) -> Result<CursorImpl<&mut StatementImpl<'o>>, Error> {
+        execute_special_columns(
+            &mut self.statement,
+            identifier_type,
+            &SqlText::new(catalog_name),
+            &SqlText::new(schema_name),
+            &SqlText::new(table_name),
+            scope,
+            nullable,
+        )
+    }
+
+    /// A cursor describing the privileges on the tables matching the patterns. `schema_name` and
+    /// `table_name` support as placeholder `%` for multiple characters or `_` for a single
+    /// character. Use `\` to escape. Empty strings do not restrict the result. The returned cursor
+    /// has the columns: `TABLE_CAT`, `TABLE_SCHEM`, `TABLE_NAME`, `GRANTOR`, `GRANTEE`,
+    /// `PRIVILEGE`, `IS_GRANTABLE`.
+    ///
+    /// See: <https://learn.microsoft.com/sql/odbc/reference/syntax/sqltableprivileges-function>
+    pub fn table_privileges(
+        &mut self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+    ) -> Result<CursorImpl<&mut StatementImpl<'o>>, Error> {
+        execute_table_privileges(
+            &mut self.statement,
+            &SqlText::new(catalog_name),
+            &SqlText::new(schema_name),
+            &SqlText::new(table_name),
+        )
+    }
+
+    /// A cursor describing the privileges on the columns of a table. `column_name` supports as
+    /// placeholder `%` for multiple characters or `_` for a single character. Use `\` to escape.
+    /// The other arguments are not search patterns. Empty strings do not restrict the result. The
+    /// returned cursor has the columns: `TABLE_CAT`, `TABLE_SCHEM`, `TABLE_NAME`, `COLUMN_NAME`,
+    /// `GRANTOR`, `GRANTEE`, `PRIVILEGE`, `IS_GRANTABLE`.
+    ///
+    /// See: <https://learn.microsoft.com/sql/odbc/reference/syntax/sqlcolumnprivileges-function>
+    pub fn column_privileges(
+        &mut self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+        column_name: &str,
+    ) -> Result<CursorImpl<&mut StatementImpl<'o>>, Error> {
+        execute_column_privileges(
+            &mut self.statement,
+            &SqlText::new(catalog_name),
+            &SqlText::new(schema_name),
+            &SqlText::new(table_name),
+            &SqlText::new(column_name),
+        )
+    }
+
+    /// Returns the column names that make up the primary key for a table. The returned cursor has
+    /// the columns: `TABLE_CAT`, `TABLE_SCHEM`, `TABLE_NAME`, `COLUMN_NAME`, `KEY_SEQ`, `PK_NAME`.
+    /// The result set is ordered by `TABLE_CAT`, `TABLE_SCHEM`, `TABLE_NAME` and `KEY_SEQ`.
+    ///
+    /// The arguments are not search patterns. Empty strings are passed as `NULL` to the driver, i.e.
+    /// an empty `catalog_name` or `schema_name` does not restrict the result. `table_name` must not
+    /// be empty.
+    ///
+    /// See: <https://learn.microsoft.com/sql/odbc/reference/syntax/sqlprimarykeys-function>
+    pub fn primary_keys(
+        &mut self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+    ) -> Result<CursorImpl<&mut StatementImpl<'o>>, Error> {
+        execute_primary_keys(
+            &mut self.statement,
+            &SqlText::new(catalog_name),
+            &SqlText::new(schema_name),
+            &SqlText::new(table_name),
+        )
+    }
+
+    /// Retrieves statistics about a single table and the indexes associated with it. The returned
+    /// cursor has the columns: `TABLE_CAT`, `TABLE_SCHEM`, `TABLE_NAME`, `NON_UNIQUE`,
+    /// `INDEX_QUALIFIER`, `INDEX_NAME`, `TYPE`, `ORDINAL_POSITION`, `COLUMN_NAME`, `ASC_OR_DESC`,
+    /// `CARDINALITY`, `PAGES`, `FILTER_CONDITION`. Use [`crate::StatisticsRow`] to fetch the result
+    /// set row by row into typed rows.
+    ///
+    /// The arguments are not search patterns. Empty strings are passed as `NULL` to the driver, i.e.
+    /// an empty `catalog_name` or `schema_name` does not restrict the result. `table_name` must not
+    /// be empty.
+    ///
+    /// * `unique_only`: If `true` only unique indexes are returned, otherwise all indexes.
+    /// * `ensure_accuracy`: If `true` the driver retrieves `CARDINALITY` and `PAGES`
+    ///   unconditionally, otherwise only if they are readily available.
+    ///
+    /// See: <https://learn.microsoft.com/sql/odbc/reference/syntax/sqlstatistics-function>
+    pub fn statistics(
+        &mut self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+        unique_only: bool,
+        ensure_accuracy: bool,
+    ) -> Result<CursorImpl<&mut StatementImpl<'o>>, Error> {
+        execute_statistics(
+            &mut self.statement,
+            &SqlText::new(catalog_name),
+            &SqlText::new(schema_name),
+            &SqlText::new(table_name),
+            unique_only,
+            ensure_accuracy,
+        )
+    }
+
+    /// A cursor describing the procedures matching the patterns. `schema_name` and `procedure_name`
+    /// support as placeholder `%` for multiple characters or `_` for a single character. Use `\`
+    /// to escape. Empty strings do not restrict the result. The returned cursor has the columns:
+    /// `PROCEDURE_CAT`, `PROCEDURE_SCHEM`, `PROCEDURE_NAME`, `NUM_INPUT_PARAMS`,
+    /// `NUM_OUTPUT_PARAMS`, `NUM_RESULT_SETS`, `REMARKS`, `PROCEDURE_TYPE`.
+    ///
+    /// See: <https://learn.microsoft.com/sql/odbc/reference/syntax/sqlprocedures-function>
+    pub fn procedures(
+        &mut self,
+        catalog_name: &str,
+        schema_name: &str,
+        procedure_name: &str,
+    ) -> Result<CursorImpl<&mut StatementImpl<'o>>, Error> {
+        execute_procedures(
+            &mut self.statement,
+            &SqlText::new(catalog_name),
+            &SqlText::new(schema_name),
+            &SqlText::new(procedure_name),
+        )
+    }
+
+    /// A cursor describing the parameters and result set columns of the procedures matching the
+    /// patterns. `schema_name`, `procedure_name` and `column_name` support as placeholder `%` for
+    /// multiple characters or `_` for a single character. Use `\` to escape. Empty strings do not
+    /// restrict the result. The returned cursor has the columns: `PROCEDURE_CAT`,
+    /// `PROCEDURE_SCHEM`, `PROCEDURE_NAME`, `COLUMN_NAME`, `COLUMN_TYPE`, `DATA_TYPE`, `TYPE_NAME`,
+    /// `COLUMN_SIZE`, `BUFFER_LENGTH`, `DECIMAL_DIGITS`, `NUM_PREC_RADIX`, `NULLABLE`, `REMARKS`,
+    /// `COLUMN_DEF`, `SQL_DATA_TYPE`, `SQL_DATETIME_SUB`, `CHAR_OCTET_LENGTH`, `ORDINAL_POSITION`,
+    /// `IS_NULLABLE`. Use [`crate::ProcedureColumnRow`] to fetch the result set row by row into
+    /// typed rows, e.g. in order to check the parameter modes of a procedure.
+    ///
+    /// In addition to that there may be a number of columns specific to the data source.
+    ///
+    /// See: <https://learn.microsoft.com/sql/odbc/reference/syntax/sqlprocedurecolumns-function>
+    pub fn procedure_columns(
+        &mut self,
+        catalog_name: &str,
+        schema_name: &str,
+        procedure_name: &str,
+        column_name: &str,
+    ) -> Result<CursorImpl<&mut StatementImpl<'o>>, Error> {
+        execute_procedure_columns(
+            &mut self.statement,
+            &SqlText::new(catalog_name),
+            &SqlText::new(schema_name),
+            &SqlText::new(procedure_name),
+            &SqlText::new(column_name),
+        )
+    }
+
+    /// Like [`Self::tables`], but yields typed [`TableInfo`] records rather than a raw cursor.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error};
+    ///
+    /// fn print_tables(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     let mut stmt = conn.preallocate()?;
+    ///     for table in stmt.table_infos("", "", "", "TABLE")? {
+    ///         println!("{}", table?.name);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn table_infos(
+        &mut self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+        table_type: &str,
+    ) -> Result<CatalogIter<CursorImpl<&mut StatementImpl<'o>>, TableInfo>, Error> {
+        CatalogIter::new(self.tables(catalog_name, schema_name, table_name, table_type)?)
+    }
+
+    /// Like [`Self::columns`], but yields typed [`ColumnInfo`] records rather than a raw cursor.
+    pub fn column_infos(
+        &mut self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+        column_name: &str,
+    ) -> Result<CatalogIter<CursorImpl<&mut StatementImpl<'o>>, ColumnInfo>, Error> {
+        CatalogIter::new(self.columns(catalog_name, schema_name, table_name, column_name)?)
+    }
+
+    /// Like [`Self::foreign_keys`], but yields typed [`ForeignKeyInfo`] records rather than a raw
+    /// cursor.
+    pub fn foreign_key_infos(
+        &mut self,
+        pk_catalog_name: &str,
+        pk_schema_name: &str,
+        pk_table_name: &str,
+        fk_catalog_name: &str,
+        fk_schema_name: &str,
+        fk_table_name: &str,
+    ) -> Result<CatalogIter<CursorImpl<&mut StatementImpl<'o>>, ForeignKeyInfo>, Error> {
+        CatalogIter::new(self.foreign_keys(
+            pk_catalog_name,
+            pk_schema_name,
+            pk_table_name,
+            fk_catalog_name,
+            fk_schema_name,
+            fk_table_name,
+        )?)
+    }
+
     /// Number of rows affected by the last `INSERT`, `UPDATE` or `DELETE` statment. May return
     /// `None` if row count is not available. Some drivers may also allow to use this to determine
     /// how many rows have been fetched using `SELECT`. Most drivers however only know how many rows
@@ -2,7 +2,8 @@ use crate::{
     buffers::{AnyBuffer, BufferDesc, ColumnBuffer, TextColumn},
     execute::execute_with_parameters,
     handles::{AsStatementRef, HasDataType, ParameterDescription, Statement, StatementRef},
-    ColumnarBulkInserter, CursorImpl, Error, ParameterCollectionRef, ResultSetMetadata,
+    CancelHandle, ColumnarBulkInserter, CursorImpl, Error, ParameterCollectionRef,
+    ResultSetMetadata, StatementOptions,
 };
 
 /// A prepared query. Prepared queries are useful if the similar queries should executed more than
@@ -48,6 +49,33 @@ where
         execute_with_parameters(move || Ok(stmt), None, params)
     }
 
+    /// Apply `options` to the statement. The options stay in effect for all subsequent executions.
+    ///
+    /// Only [`StatementOptions::query_timeout_sec`] and [`StatementOptions::max_rows`] can be
+    /// changed once the statement is prepared. The cursor type, scrollability and concurrency must
+    /// be specified before, using [`crate::Connection::prepare_with_options`].
+    ///
+    /// # Return
+    ///
+    /// [`Error::CursorOptionsAfterPrepare`] if any of [`StatementOptions::cursor_type`],
+    /// [`StatementOptions::scrollable`] or [`StatementOptions::concurrency`] is `Some`. Nothing is
+    /// applied in that case.
+    pub fn set_options(&mut self, options: StatementOptions) -> Result<(), Error> {
+        if options.cursor_type.is_some()
+            || options.scrollable.is_some()
+            || options.concurrency.is_some()
+        {
+            return Err(Error::CursorOptionsAfterPrepare);
+        }
+        options.apply(&mut self.statement.as_stmt_ref())
+    }
+
+    /// A handle which can be used to cancel the execution of this statement from another thread.
+    /// See [`CancelHandle`].
+    pub fn cancel_handle(&mut self) -> CancelHandle {
+        CancelHandle::new(self.statement.as_stmt_ref().as_sys())
+    }
+
     /// Describes parameter marker associated with a prepared SQL statement.
     ///
     /// # Parameters
@@ -1,6 +1,17 @@
-use std::future::Future;
+use std::{future::Future, thread, time::Duration};
 
-use crate::handles::SqlResult;
+use log::{debug, warn};
+use odbc_sys::HStmt;
+
+use crate::{
+    handles::{Connection, SqlResult, Statement, StatementRef},
+    Error,
+};
+
+#[cfg(feature = "odbc_version_3_80")]
+use odbc_sys::HDbc;
+#[cfg(feature = "odbc_version_3_80")]
+use std::mem::ManuallyDrop;
 
 /// Governs the behaviour of of polling in async functions.
 ///
@@ -26,15 +37,157 @@ where
     }
 }
 
-pub async fn wait_for<F, O>(mut f: F, sleep: &mut impl Sleep) -> SqlResult<O>
+/// Calls `f` until it no longer reports [`SqlResult::StillExecuting`], awaiting `sleep` in
+/// between. `f` is expected to invoke an asynchronous ODBC function on `statement`.
+///
+/// Should the returned future be dropped before the asynchronous operation finished (e.g. because
+/// it lost a `select!` or a timeout elapsed), the operation is canceled using `SQLCancel`. Since
+/// we can not await in drop, `f` is then polled in a blocking fashion until the driver confirms
+/// the cancellation. This way the statement is left in a reusable state rather than still
+/// executing. Please note that dropping the future therefore blocks the current thread until the
+/// driver confirms the cancellation. See [`cancel_and_wait`].
+pub async fn wait_for<F, O>(statement: HStmt, f: F, sleep: &mut impl Sleep) -> SqlResult<O>
+where
+    F: FnMut() -> SqlResult<O>,
+{
+    poll(f, move || cancel_statement(statement), sleep).await
+}
+
+/// Calls `f` with asynchronous execution of connection functions enabled
+/// (`SQL_ATTR_ASYNC_DBC_FUNCTIONS_ENABLE`), awaiting `sleep` in between polling. `f` is expected to
+/// invoke an ODBC function on `connection`, like `SQLDriverConnect` or `SQLEndTran`.
+///
+/// Should the driver not support asynchronous execution on connection level, `f` is executed
+/// blocking instead. Asynchronous execution is disabled again, once the function completed, so
+/// blocking calls on the connection keep working. Should the returned future be dropped before the
+/// operation finished, it is canceled using `SQLCancelHandle`.
+pub async fn wait_for_connection<F, O>(
+    connection: &mut Connection<'_>,
+    mut f: F,
+    sleep: &mut impl Sleep,
+) -> Result<O, Error>
+where
+    F: FnMut(&mut Connection<'_>) -> SqlResult<O>,
+{
+    #[cfg(feature = "odbc_version_3_80")]
+    if let Err(e) = connection
+        .set_async_dbc_functions_enable(true)
+        .into_result(connection)
+    {
+        debug!("Asynchronous connection functions are not supported. Blocking instead: {e}");
+    } else {
+        let hdbc = connection.as_sys();
+        let _disable_on_drop = AsyncDbcFunctions { connection: hdbc };
+        let cancel = move || {
+            // We must not free the handle, we are only borrowing it.
+            let conn = ManuallyDrop::new(unsafe { Connection::new(hdbc) });
+            conn.cancel().into_result(&*conn)
+        };
+        let ret = poll(|| f(connection), cancel, sleep).await;
+        return ret.into_result(connection);
+    }
+    #[cfg(not(feature = "odbc_version_3_80"))]
+    let _ = sleep;
+    f(connection).into_result(connection)
+}
+
+/// Disables asynchronous execution of connection functions once dropped.
+#[cfg(feature = "odbc_version_3_80")]
+struct AsyncDbcFunctions {
+    connection: HDbc,
+}
+
+#[cfg(feature = "odbc_version_3_80")]
+impl Drop for AsyncDbcFunctions {
+    fn drop(&mut self) {
+        // We must not free the handle, we are only borrowing it.
+        let conn = ManuallyDrop::new(unsafe { Connection::new(self.connection) });
+        if let Err(e) = conn
+            .set_async_dbc_functions_enable(false)
+            .into_result(&*conn)
+        {
+            warn!("Failed to disable asynchronous execution of connection functions: {e}");
+        }
+    }
+}
+
+async fn poll<F, C, O>(f: F, cancel: C, sleep: &mut impl Sleep) -> SqlResult<O>
 where
     F: FnMut() -> SqlResult<O>,
+    C: FnMut() -> Result<(), Error>,
 {
-    let mut ret = (f)();
+    let mut guard = CancelOnDrop {
+        f,
+        cancel,
+        still_executing: false,
+    };
+    let mut ret = (guard.f)();
     // Wait for operation to finish, using polling method
     while matches!(ret, SqlResult::StillExecuting) {
+        guard.still_executing = true;
         sleep.next_poll().await;
-        ret = (f)();
+        ret = (guard.f)();
     }
+    guard.still_executing = false;
     ret
 }
+
+/// Cancels an asynchronous operation, should it be still executing once this guard is dropped.
+struct CancelOnDrop<F, C, O>
+where
+    F: FnMut() -> SqlResult<O>,
+    C: FnMut() -> Result<(), Error>,
+{
+    /// Polls the asynchronous operation
+    f: F,
+    /// Requests cancellation of the asynchronous operation
+    cancel: C,
+    still_executing: bool,
+}
+
+impl<F, C, O> Drop for CancelOnDrop<F, C, O>
+where
+    F: FnMut() -> SqlResult<O>,
+    C: FnMut() -> Result<(), Error>,
+{
+    fn drop(&mut self) {
+        if !self.still_executing {
+            return;
+        }
+        cancel_and_wait(&mut self.cancel, &mut self.f);
+    }
+}
+
+/// Cancels an asynchronous operation which is still executing, because nobody is interested in its
+/// result anymore. Since we can not await (e.g. in drop), `f` is polled in a blocking fashion until
+/// the driver confirms the cancellation. This blocks the current thread for as long as the driver
+/// takes to confirm the cancellation.
+///
+/// Should requesting the cancellation fail, the driver would never confirm it and we would block
+/// for as long as the operation takes. In that case an error is logged and this function returns
+/// right away, leaving the statement to be freed by its owner.
+pub fn cancel_and_wait<O>(
+    cancel: impl FnOnce() -> Result<(), Error>,
+    mut f: impl FnMut() -> SqlResult<O>,
+) {
+    if let Err(e) = cancel() {
+        warn!(
+            "Failed to cancel asynchronous operation on dropped future: {e}. Not waiting for the \
+            operation to finish."
+        );
+        return;
+    }
+    // The original function must be called until it no longer returns
+    // `SQL_STILL_EXECUTING`. After a successful cancellation it reports `HY008`
+    // (operation canceled), which we ignore, since nobody is interested in the result.
+    while matches!(f(), SqlResult::StillExecuting) {
+        thread::sleep(Duration::from_millis(1));
+    }
+    debug!("Canceled asynchronous operation of dropped future.");
+}
+
+/// Cancels the function executing asynchronously on `statement` using `SQLCancel`.
+pub fn cancel_statement(statement: HStmt) -> Result<(), Error> {
+    let stmt = unsafe { StatementRef::new(statement) };
+    stmt.cancel().into_result(&stmt)
+}
@@ -0,0 +1,104 @@
+use crate::{handles::Statement, Error};
+
+/// Options to be applied to a statement before executing it. See e.g.
+/// [`crate::Connection::execute_with_options`], [`crate::Connection::prepare_with_options`],
+/// [`crate::Prepared::set_options`] or [`crate::Preallocated::set_options`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StatementOptions {
+    /// Number of seconds to wait for an SQL statement to execute before returning to the
+    /// application. If `None` or `Some(0)` there is no timeout. If the timeout expires, the
+    /// operation fails with [`Error::Timeout`].
+    ///
+    /// This corresponds to the `SQL_ATTR_QUERY_TIMEOUT` attribute in the ODBC specification.
+    pub query_timeout_sec: Option<usize>,
+    /// Maximum number of rows to return to the application for a `SELECT` statement. If `None`
+    /// or `Some(0)` all rows are returned. Data sources may not support this option.
+    ///
+    /// This corresponds to the `SQL_ATTR_MAX_ROWS` attribute in the ODBC specification.
+    pub max_rows: Option<usize>,
+    /// Type of cursor opened by executing a query. If `None` the driver default is used, which is
+    /// usually [`CursorType::ForwardOnly`]. Choose any other type to be able to move the cursor
+    /// backwards or to an absolute position, e.g. using [`crate::BlockCursor::fetch_absolute`].
+    /// Data sources may not support all cursor types and substitute a different one.
+    ///
+    /// This corresponds to the `SQL_ATTR_CURSOR_TYPE` attribute in the ODBC specification.
+    pub cursor_type: Option<CursorType>,
+    /// If `Some(true)`, cursors opened by executing a query must be scrollable, leaving the choice
+    /// of the cursor type to the driver. An alternative to specifying [`Self::cursor_type`].
+    ///
+    /// This corresponds to the `SQL_ATTR_CURSOR_SCROLLABLE` attribute in the ODBC specification.
+    pub scrollable: Option<bool>,
+    /// Concurrency control used by cursors opened by executing a query. If `None` the driver
+    /// default is used, which is usually [`Concurrency::ReadOnly`]. Choose any other mode to be able
+    /// to update, delete or add rows using a cursor, e.g. via [`crate::BlockCursor::set_pos`].
+    ///
+    /// This corresponds to the `SQL_ATTR_CONCURRENCY` attribute in the ODBC specification.
+    pub concurrency: Option<Concurrency>,
+}
+
+impl StatementOptions {
+    /// Set the attributes corresponding to the statement options to an allocated statement handle.
+    /// Usually you would rather pass the options to one of the methods creating a statement.
+    pub fn apply(&self, statement: &mut impl Statement) -> Result<(), Error> {
+        if let Some(timeout_sec) = self.query_timeout_sec {
+            statement
+                .set_query_timeout_sec(timeout_sec)
+                .into_result(statement)?;
+        }
+        if let Some(max_rows) = self.max_rows {
+            statement.set_max_rows(max_rows).into_result(statement)?;
+        }
+        if let Some(cursor_type) = self.cursor_type {
+            statement
+                .set_cursor_type(cursor_type as usize)
+                .into_result(statement)?;
+        }
+        if let Some(scrollable) = self.scrollable {
+            statement
+                .set_cursor_scrollable(scrollable)
+                .into_result(statement)?;
+        }
+        if let Some(concurrency) = self.concurrency {
+            statement
+                .set_concurrency(concurrency as usize)
+                .into_result(statement)?;
+        }
+        Ok(())
+    }
+}
+
+/// Type of the cursor opened by executing a query. See [`StatementOptions::cursor_type`].
+///
+/// See: <https://learn.microsoft.com/sql/odbc/reference/develop-app/scrollable-cursor-types>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(usize)]
+pub enum CursorType {
+    /// `SQL_CURSOR_FORWARD_ONLY`. The cursor only scrolls forward.
+    ForwardOnly = 0,
+    /// `SQL_CURSOR_KEYSET_DRIVEN`. The membership and order of the result set is fixed once the
+    /// cursor is opened, while changes to the values of the rows are visible.
+    KeysetDriven = 1,
+    /// `SQL_CURSOR_DYNAMIC`. Changes to membership, order and values of the result set are
+    /// visible.
+    Dynamic = 2,
+    /// `SQL_CURSOR_STATIC`. The result set is fixed once the cursor is opened.
+    Static = 3,
+}
+
+/// Concurrency control of the cursor opened by executing a query. See
+/// [`StatementOptions::concurrency`].
+///
+/// See: <https://learn.microsoft.com/sql/odbc/reference/develop-app/concurrency-control>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(usize)]
+pub enum Concurrency {
+    /// `SQL_CONCUR_READ_ONLY`. The cursor is read-only. No updates are allowed.
+    ReadOnly = 1,
+    /// `SQL_CONCUR_LOCK`. The cursor uses the lowest level of locking sufficient to ensure that the
+    /// row can be updated.
+    Lock = 2,
+    /// `SQL_CONCUR_ROWVER`. Optimistic concurrency control, comparing row versions.
+    RowVersion = 3,
+    /// `SQL_CONCUR_VALUES`. Optimistic concurrency control, comparing values.
+    Values = 4,
+}
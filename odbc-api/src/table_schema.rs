@@ -0,0 +1,471 @@
+use crate::{
+    catalog::{small_int, text},
+    CatalogIter, CatalogRecord, ColumnInfo, Connection, CursorRow, Error, ForeignKeyInfo,
+    ReferentialAction, StatisticsRow,
+};
+
+/// Shape of a table, aggregated from the results of several catalog functions. Returned by
+/// [`crate::Connection::describe_table`].
+///
+/// Two descriptions can be compared with each other, e.g. in order to find out whether the schema
+/// of a table differs between two data sources. Please note that `==` also compares where the table
+/// is located, i.e. its catalog and schema, which is also part of each [`ColumnInfo`]. Use
+/// [`Self::same_shape`] to compare only the shape of two tables. Enable the `serde` feature in
+/// order to serialize and deserialize it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableSchema {
+    /// Catalog of the table. `None` if not applicable to the data source.
+    pub catalog: Option<String>,
+    /// Schema of the table. `None` if not applicable to the data source.
+    pub schema: Option<String>,
+    /// Name of the table, as reported by the data source.
+    pub name: String,
+    /// Columns of the table, in the order of their ordinal position.
+    pub columns: Vec<ColumnInfo>,
+    /// Primary key of the table. `None` if the table does not have one.
+    pub primary_key: Option<PrimaryKey>,
+    /// Foreign keys of the table, ordered by name and referenced table.
+    pub foreign_keys: Vec<ForeignKey>,
+    /// Indexes of the table, ordered by name. Depending on the data source this includes the
+    /// indexes backing the primary key and unique constraints.
+    pub indexes: Vec<Index>,
+}
+
+/// Primary key of a table. Part of [`TableSchema`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrimaryKey {
+    /// Name of the primary key constraint. `None` if not applicable to the data source.
+    pub name: Option<String>,
+    /// Names of the columns making up the primary key, in the order of their position within the
+    /// key.
+    pub columns: Vec<String>,
+}
+
+/// Foreign key of a table. Part of [`TableSchema`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ForeignKey {
+    /// Name of the foreign key constraint. `None` if not applicable to the data source.
+    pub name: Option<String>,
+    /// Names of the columns making up the foreign key, in the order of their position within the
+    /// key.
+    pub columns: Vec<String>,
+    /// Catalog of the referenced table. `None` if not applicable to the data source.
+    pub referenced_catalog: Option<String>,
+    /// Schema of the referenced table. `None` if not applicable to the data source.
+    pub referenced_schema: Option<String>,
+    /// Name of the referenced table.
+    pub referenced_table: String,
+    /// Names of the referenced columns. Same order as [`Self::columns`].
+    pub referenced_columns: Vec<String>,
+    /// Action applied if the referenced key is updated.
+    pub update_rule: Option<ReferentialAction>,
+    /// Action applied if the referenced row is deleted.
+    pub delete_rule: Option<ReferentialAction>,
+}
+
+/// Index of a table. Part of [`TableSchema`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Index {
+    /// Name of the index.
+    pub name: String,
+    /// `true` if the index values must be unique.
+    pub unique: bool,
+    /// Names of the indexed columns (or expressions), in the order of their position within the
+    /// index.
+    pub columns: Vec<String>,
+}
+
+impl TableSchema {
+    /// `true` if both tables have the same columns, primary key, foreign keys and indexes. In
+    /// contrast to `==` the name of the table, as well as the catalogs and schemas of the table and
+    /// the tables referenced by its foreign keys are ignored. This allows to compare tables across
+    /// different databases or data sources.
+    ///
+    /// Names of constraints and indexes are still compared. Depending on the data source these may
+    /// have been generated, if they have not been specified explicitly.
+    pub fn same_shape(&self, other: &TableSchema) -> bool {
+        fn column_shape(column: &ColumnInfo) -> ColumnInfo {
+            ColumnInfo {
+                catalog: None,
+                schema: None,
+                table: String::new(),
+                ..column.clone()
+            }
+        }
+        fn foreign_key_shape(foreign_key: &ForeignKey) -> ForeignKey {
+            ForeignKey {
+                referenced_catalog: None,
+                referenced_schema: None,
+                ..foreign_key.clone()
+            }
+        }
+        self.columns.len() == other.columns.len()
+            && self
+                .columns
+                .iter()
+                .zip(&other.columns)
+                .all(|(a, b)| column_shape(a) == column_shape(b))
+            && self.primary_key == other.primary_key
+            && self.foreign_keys.len() == other.foreign_keys.len()
+            && self
+                .foreign_keys
+                .iter()
+                .zip(&other.foreign_keys)
+                .all(|(a, b)| foreign_key_shape(a) == foreign_key_shape(b))
+            && self.indexes == other.indexes
+    }
+
+    pub(crate) fn fetch(
+        connection: &Connection<'_>,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+    ) -> Result<Option<Self>, Error> {
+        let mut columns = connection
+            .column_infos(catalog_name, schema_name, table_name, "")?
+            .collect::<Result<Vec<_>, _>>()?;
+        // `table_name` is treated as a search pattern by `SQLColumns`, so columns of other tables
+        // may be part of the result. Only consider the first table whose name actually matches.
+        let Some(first) = columns
+            .iter()
+            .find(|column| column.table.eq_ignore_ascii_case(table_name))
+        else {
+            return Ok(None);
+        };
+        let catalog = first.catalog.clone();
+        let schema = first.schema.clone();
+        let name = first.table.clone();
+        columns.retain(|column| {
+            column.catalog == catalog && column.schema == schema && column.table == name
+        });
+
+        let catalog_name = catalog.as_deref().unwrap_or_default();
+        let schema_name = schema.as_deref().unwrap_or_default();
+        let primary_key_columns =
+            CatalogIter::new(connection.primary_keys(catalog_name, schema_name, &name)?)?
+                .collect::<Result<Vec<_>, _>>()?;
+        let foreign_key_infos = connection
+            .foreign_key_infos("", "", "", catalog_name, schema_name, &name)?
+            .collect::<Result<Vec<_>, _>>()?;
+        let index_columns = CatalogIter::new(connection.statistics(
+            catalog_name,
+            schema_name,
+            &name,
+            false,
+            false,
+        )?)?
+        .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Some(Self::from_parts(
+            catalog,
+            schema,
+            name,
+            columns,
+            primary_key_columns,
+            foreign_key_infos,
+            index_columns,
+        )))
+    }
+
+    /// Aggregates the records of the individual catalog functions.
+    fn from_parts(
+        catalog: Option<String>,
+        schema: Option<String>,
+        name: String,
+        mut columns: Vec<ColumnInfo>,
+        mut primary_key_columns: Vec<PrimaryKeyColumn>,
+        mut foreign_key_infos: Vec<ForeignKeyInfo>,
+        mut index_columns: Vec<IndexColumn>,
+    ) -> Self {
+        columns.sort_by_key(|column| column.ordinal_position);
+
+        primary_key_columns.sort_by_key(|column| column.key_seq);
+        let primary_key = (!primary_key_columns.is_empty()).then(|| PrimaryKey {
+            name: primary_key_columns[0].name.clone(),
+            columns: primary_key_columns
+                .into_iter()
+                .map(|column| column.column)
+                .collect(),
+        });
+
+        foreign_key_infos.sort_by_key(|info| info.key_seq);
+        let mut foreign_keys: Vec<ForeignKey> = Vec::new();
+        for info in foreign_key_infos {
+            let existing = foreign_keys.iter_mut().find(|fk| {
+                let same_key = match &info.fk_name {
+                    Some(_) => fk.name == info.fk_name,
+                    // Unnamed keys referencing the same table can only be told apart by `KEY_SEQ`,
+                    // which restarts at 1 for each key.
+                    None => {
+                        fk.name.is_none()
+                            && info.key_seq > 1
+                            && fk.columns.len() == usize::from(info.key_seq - 1)
+                    }
+                };
+                same_key
+                    && fk.referenced_catalog == info.pk_catalog
+                    && fk.referenced_schema == info.pk_schema
+                    && fk.referenced_table == info.pk_table
+            });
+            if let Some(fk) = existing {
+                fk.columns.push(info.fk_column);
+                fk.referenced_columns.push(info.pk_column);
+            } else {
+                foreign_keys.push(ForeignKey {
+                    name: info.fk_name,
+                    columns: vec![info.fk_column],
+                    referenced_catalog: info.pk_catalog,
+                    referenced_schema: info.pk_schema,
+                    referenced_table: info.pk_table,
+                    referenced_columns: vec![info.pk_column],
+                    update_rule: info.update_rule,
+                    delete_rule: info.delete_rule,
+                });
+            }
+        }
+        foreign_keys
+            .sort_by(|a, b| (&a.name, &a.referenced_table).cmp(&(&b.name, &b.referenced_table)));
+
+        index_columns.retain(|column| column.statistics_type != StatisticsRow::TABLE_STAT);
+        index_columns.sort_by_key(|column| column.ordinal_position);
+        let mut indexes: Vec<Index> = Vec::new();
+        for column in index_columns {
+            let (Some(index_name), Some(column_name)) = (column.index_name, column.column_name)
+            else {
+                continue;
+            };
+            if let Some(index) = indexes.iter_mut().find(|index| index.name == index_name) {
+                index.columns.push(column_name);
+            } else {
+                indexes.push(Index {
+                    name: index_name,
+                    unique: column.non_unique == Some(0),
+                    columns: vec![column_name],
+                });
+            }
+        }
+        indexes.sort_by(|a, b| a.name.cmp(&b.name));
+
+        TableSchema {
+            catalog,
+            schema,
+            name,
+            columns,
+            primary_key,
+            foreign_keys,
+            indexes,
+        }
+    }
+}
+
+/// Record of the result set of `SQLPrimaryKeys`.
+struct PrimaryKeyColumn {
+    column: String,
+    key_seq: i16,
+    name: Option<String>,
+}
+
+impl CatalogRecord for PrimaryKeyColumn {
+    fn read(row: &mut CursorRow<'_>, _num_cols: u16) -> Result<Self, Error> {
+        // Skip `TABLE_CAT`, `TABLE_SCHEM` and `TABLE_NAME`
+        Ok(PrimaryKeyColumn {
+            column: text(row, 4)?.unwrap_or_default(),
+            key_seq: small_int(row, 5)?.unwrap_or_default(),
+            name: text(row, 6)?,
+        })
+    }
+}
+
+/// Record of the result set of `SQLStatistics`. Only the columns relevant to [`Index`].
+struct IndexColumn {
+    non_unique: Option<i16>,
+    index_name: Option<String>,
+    statistics_type: i16,
+    ordinal_position: Option<i16>,
+    column_name: Option<String>,
+}
+
+impl CatalogRecord for IndexColumn {
+    fn read(row: &mut CursorRow<'_>, _num_cols: u16) -> Result<Self, Error> {
+        // Skip `TABLE_CAT`, `TABLE_SCHEM`, `TABLE_NAME` and `INDEX_QUALIFIER`
+        let non_unique = small_int(row, 4)?;
+        let index_name = text(row, 6)?;
+        let statistics_type = small_int(row, 7)?.unwrap_or_default();
+        let ordinal_position = small_int(row, 8)?;
+        let column_name = text(row, 9)?;
+        Ok(IndexColumn {
+            non_unique,
+            index_name,
+            statistics_type,
+            ordinal_position,
+            column_name,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ForeignKeyInfo;
+
+    use super::{ForeignKey, Index, IndexColumn, PrimaryKey, PrimaryKeyColumn, TableSchema};
+
+    fn foreign_key_info(
+        fk_name: &str,
+        fk_column: &str,
+        pk_column: &str,
+        seq: u16,
+    ) -> ForeignKeyInfo {
+        ForeignKeyInfo {
+            pk_catalog: None,
+            pk_schema: None,
+            pk_table: "parent".to_string(),
+            pk_column: pk_column.to_string(),
+            fk_catalog: None,
+            fk_schema: None,
+            fk_table: "child".to_string(),
+            fk_column: fk_column.to_string(),
+            key_seq: seq,
+            update_rule: None,
+            delete_rule: None,
+            fk_name: Some(fk_name.to_string()),
+            pk_name: None,
+        }
+    }
+
+    fn index_column(name: &str, column: &str, position: i16) -> IndexColumn {
+        IndexColumn {
+            non_unique: Some(0),
+            index_name: Some(name.to_string()),
+            statistics_type: 3,
+            ordinal_position: Some(position),
+            column_name: Some(column.to_string()),
+        }
+    }
+
+    #[test]
+    fn group_keys_and_indexes_by_name() {
+        let primary_key_columns = vec![
+            PrimaryKeyColumn {
+                column: "b".to_string(),
+                key_seq: 2,
+                name: Some("pk".to_string()),
+            },
+            PrimaryKeyColumn {
+                column: "a".to_string(),
+                key_seq: 1,
+                name: Some("pk".to_string()),
+            },
+        ];
+        // Drivers order foreign key columns by `KEY_SEQ` first, interleaving the keys.
+        let foreign_key_infos = vec![
+            foreign_key_info("fk_2", "d", "y", 1),
+            foreign_key_info("fk_1", "a", "x", 1),
+            foreign_key_info("fk_1", "b", "y", 2),
+        ];
+        let index_columns = vec![
+            // Table statistics do not describe an index
+            IndexColumn {
+                non_unique: None,
+                index_name: None,
+                statistics_type: 0,
+                ordinal_position: None,
+                column_name: None,
+            },
+            index_column("idx", "c", 2),
+            index_column("idx", "b", 1),
+        ];
+
+        let schema = TableSchema::from_parts(
+            None,
+            None,
+            "child".to_string(),
+            Vec::new(),
+            primary_key_columns,
+            foreign_key_infos,
+            index_columns,
+        );
+
+        assert_eq!(
+            Some(PrimaryKey {
+                name: Some("pk".to_string()),
+                columns: vec!["a".to_string(), "b".to_string()]
+            }),
+            schema.primary_key
+        );
+        let fk_columns: Vec<_> = schema
+            .foreign_keys
+            .iter()
+            .map(|ForeignKey { columns, .. }| columns.join(","))
+            .collect();
+        assert_eq!(vec!["a,b", "d"], fk_columns);
+        assert_eq!(
+            vec![Index {
+                name: "idx".to_string(),
+                unique: true,
+                columns: vec!["b".to_string(), "c".to_string()]
+            }],
+            schema.indexes
+        );
+    }
+
+    #[test]
+    fn group_unnamed_foreign_keys_by_key_seq() {
+        let unnamed = |fk_column, pk_column, seq| ForeignKeyInfo {
+            fk_name: None,
+            ..foreign_key_info("", fk_column, pk_column, seq)
+        };
+        // Two unnamed keys referencing the same table, interleaved by `KEY_SEQ`.
+        let foreign_key_infos = vec![
+            unnamed("a", "x", 1),
+            unnamed("c", "x", 1),
+            unnamed("b", "y", 2),
+            unnamed("d", "y", 2),
+        ];
+
+        let schema = TableSchema::from_parts(
+            None,
+            None,
+            "child".to_string(),
+            Vec::new(),
+            Vec::new(),
+            foreign_key_infos,
+            Vec::new(),
+        );
+
+        let fk_columns: Vec<_> = schema
+            .foreign_keys
+            .iter()
+            .map(|ForeignKey { columns, .. }| columns.join(","))
+            .collect();
+        assert_eq!(vec!["a,b", "c,d"], fk_columns);
+    }
+
+    #[test]
+    fn same_shape_ignores_location() {
+        let in_catalog = |catalog: &str| {
+            let foreign_key_infos = vec![ForeignKeyInfo {
+                pk_catalog: Some(catalog.to_string()),
+                ..foreign_key_info("fk", "a", "x", 1)
+            }];
+            TableSchema::from_parts(
+                Some(catalog.to_string()),
+                None,
+                "child".to_string(),
+                Vec::new(),
+                Vec::new(),
+                foreign_key_infos,
+                Vec::new(),
+            )
+        };
+
+        let a = in_catalog("first");
+        let b = in_catalog("second");
+
+        assert_ne!(a, b);
+        assert!(a.same_shape(&b));
+    }
+}
@@ -0,0 +1,275 @@
+use log::error;
+
+use crate::{
+    handles::StatementImpl, Connection, CursorImpl, Error, ParameterCollectionRef, Preallocated,
+    Prepared,
+};
+
+/// Guard around a transaction opened with [`Connection::begin_transaction`]. Switches the
+/// connection into manual-commit mode for its lifetime. Unless [`Transaction::commit`] is called,
+/// the transaction is rolled back once the guard goes out of scope, e.g. due to an early return
+/// caused by the `?` operator or a panic. Afterwards the connection is switched back to the
+/// commit mode it had been in before the transaction has been started.
+///
+/// Statements are executed within the transaction using [`Transaction::execute`],
+/// [`Transaction::prepare`] or [`Transaction::preallocate`]. The guard intentionally does not offer
+/// the methods of [`Connection`] which change the transaction state of the connection, like
+/// [`Connection::commit`] or [`Connection::set_autocommit`].
+///
+/// # Example
+///
+/// ```no_run
+/// use odbc_api::{Connection, Error};
+///
+/// fn transfer(conn: &Connection<'_>) -> Result<(), Error> {
+///     let transaction = conn.begin_transaction()?;
+///     transaction.execute("UPDATE Accounts SET Balance = Balance - 10 WHERE Id = 1", ())?;
+///     transaction.execute("UPDATE Accounts SET Balance = Balance + 10 WHERE Id = 2", ())?;
+///     // If we never reach this line, both updates are rolled back.
+///     transaction.commit()
+/// }
+/// ```
+pub struct Transaction<'a, 'c> {
+    connection: &'a Connection<'c>,
+    /// Auto-commit mode of the connection before the transaction has been started.
+    restore_autocommit: bool,
+    /// `true` once the transaction has been explicitly committed or rolled back.
+    finished: bool,
+}
+
+impl<'a, 'c> Transaction<'a, 'c> {
+    pub(crate) fn new(connection: &'a Connection<'c>) -> Result<Self, Error> {
+        let restore_autocommit = connection.is_autocommit()?;
+        if restore_autocommit {
+            connection.set_autocommit(false)?;
+        }
+        Ok(Self {
+            connection,
+            restore_autocommit,
+            finished: false,
+        })
+    }
+
+    /// Commit the transaction and switch the connection back to its previous commit mode. Should
+    /// the commit fail, the transaction is rolled back and the commit mode is restored, as if the
+    /// guard had been dropped.
+    pub fn commit(mut self) -> Result<(), Error> {
+        self.connection.commit()?;
+        // Only now the transaction is finished. Had the commit failed, `drop` takes care of
+        // rolling back and restoring the commit mode.
+        self.finished = true;
+        self.restore_autocommit()
+    }
+
+    /// Roll back the transaction and switch the connection back to its previous commit mode. Same
+    /// as dropping the guard, yet errors are reported to the caller rather than just being logged.
+    pub fn rollback(mut self) -> Result<(), Error> {
+        self.connection.rollback()?;
+        self.finished = true;
+        self.restore_autocommit()
+    }
+
+    /// Create a savepoint within the transaction. Dropping the returned guard without calling
+    /// [`Savepoint::release`] rolls back all changes made after the savepoint has been created,
+    /// without affecting the changes made before.
+    ///
+    /// Savepoints are created using SQL statements. ODBC does not offer an information type
+    /// describing the savepoint syntax of a data source, so as a fallback the syntax is chosen
+    /// based on the name of the database management system reported by `SQLGetInfo`. Microsoft
+    /// SQL Server uses `SAVE TRANSACTION`, all other data sources are assumed to follow the SQL
+    /// standard (`SAVEPOINT`). If the driver reports that it does not support transactions at all
+    /// [`Error::SavepointsNotSupported`] is returned.
+    ///
+    /// Please note that some data sources (e.g. Microsoft SQL Server) require at least one
+    /// statement to be executed within the transaction, before a savepoint can be created.
+    pub fn savepoint(&mut self) -> Result<Savepoint<'_, 'c>, Error> {
+        let dialect = SavepointDialect::detect(self.connection)?;
+        Savepoint::new(self.connection, dialect, 0)
+    }
+
+    /// Executes an SQL statement within the transaction. See [`Connection::execute`].
+    pub fn execute(
+        &self,
+        query: &str,
+        params: impl ParameterCollectionRef,
+    ) -> Result<Option<CursorImpl<StatementImpl<'_>>>, Error> {
+        self.connection.execute(query, params)
+    }
+
+    /// Prepares an SQL statement which is executed within the transaction. See
+    /// [`Connection::prepare`].
+    pub fn prepare(&self, query: &str) -> Result<Prepared<StatementImpl<'_>>, Error> {
+        self.connection.prepare(query)
+    }
+
+    /// Allocates a statement which is executed within the transaction. See
+    /// [`Connection::preallocate`].
+    pub fn preallocate(&self) -> Result<Preallocated<'_>, Error> {
+        self.connection.preallocate()
+    }
+
+    fn restore_autocommit(&self) -> Result<(), Error> {
+        if self.restore_autocommit {
+            self.connection.set_autocommit(true)?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Transaction<'_, '_> {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        // We must not panic in drop, especially since we might already be unwinding due to a
+        // panic. Errors are logged instead.
+        if let Err(e) = self.connection.rollback() {
+            error!("Rolling back transaction failed: {e}");
+        }
+        if let Err(e) = self.restore_autocommit() {
+            error!("Restoring auto-commit mode after transaction failed: {e}");
+        }
+    }
+}
+
+/// Guard around a savepoint created with [`Transaction::savepoint`] or [`Savepoint::savepoint`].
+/// Unless [`Savepoint::release`] is called, the transaction is rolled back to the savepoint once
+/// the guard goes out of scope.
+///
+/// Statements are executed within the transaction using [`Savepoint::execute`],
+/// [`Savepoint::prepare`] or [`Savepoint::preallocate`].
+pub struct Savepoint<'a, 'c> {
+    connection: &'a Connection<'c>,
+    dialect: SavepointDialect,
+    /// Nesting depth of the savepoint. Used to generate a name which is unique among the
+    /// savepoints alive at the same time.
+    depth: u32,
+    /// `true` once the savepoint has been explicitly released or rolled back to.
+    finished: bool,
+}
+
+impl<'a, 'c> Savepoint<'a, 'c> {
+    fn new(
+        connection: &'a Connection<'c>,
+        dialect: SavepointDialect,
+        depth: u32,
+    ) -> Result<Self, Error> {
+        let name = savepoint_name(depth);
+        let sql = match dialect {
+            SavepointDialect::Standard => format!("SAVEPOINT {name}"),
+            SavepointDialect::MsSql => format!("SAVE TRANSACTION {name}"),
+        };
+        connection.execute(&sql, ())?;
+        Ok(Self {
+            connection,
+            dialect,
+            depth,
+            finished: false,
+        })
+    }
+
+    /// Create a nested savepoint. Rolling back to this savepoint rolls back also changes made
+    /// after the nested savepoint had been created.
+    pub fn savepoint(&mut self) -> Result<Savepoint<'_, 'c>, Error> {
+        Savepoint::new(self.connection, self.dialect, self.depth + 1)
+    }
+
+    /// Executes an SQL statement within the transaction. See [`Connection::execute`].
+    pub fn execute(
+        &self,
+        query: &str,
+        params: impl ParameterCollectionRef,
+    ) -> Result<Option<CursorImpl<StatementImpl<'_>>>, Error> {
+        self.connection.execute(query, params)
+    }
+
+    /// Prepares an SQL statement which is executed within the transaction. See
+    /// [`Connection::prepare`].
+    pub fn prepare(&self, query: &str) -> Result<Prepared<StatementImpl<'_>>, Error> {
+        self.connection.prepare(query)
+    }
+
+    /// Allocates a statement which is executed within the transaction. See
+    /// [`Connection::preallocate`].
+    pub fn preallocate(&self) -> Result<Preallocated<'_>, Error> {
+        self.connection.preallocate()
+    }
+
+    /// Keep the changes made after the savepoint has been created as part of the surrounding
+    /// transaction. Microsoft SQL Server has no notion of releasing a savepoint, so this is a no-op
+    /// for it.
+    pub fn release(mut self) -> Result<(), Error> {
+        match self.dialect {
+            SavepointDialect::Standard => {
+                self.connection
+                    .execute(&format!("RELEASE SAVEPOINT {}", self.name()), ())?;
+            }
+            SavepointDialect::MsSql => (),
+        }
+        self.finished = true;
+        Ok(())
+    }
+
+    /// Roll back all changes made after the savepoint has been created. Same as dropping the
+    /// guard, yet errors are reported to the caller rather than just being logged.
+    pub fn rollback(mut self) -> Result<(), Error> {
+        self.rollback_to_savepoint()?;
+        self.finished = true;
+        Ok(())
+    }
+
+    fn rollback_to_savepoint(&self) -> Result<(), Error> {
+        let sql = match self.dialect {
+            SavepointDialect::Standard => format!("ROLLBACK TO SAVEPOINT {}", self.name()),
+            SavepointDialect::MsSql => format!("ROLLBACK TRANSACTION {}", self.name()),
+        };
+        self.connection.execute(&sql, ())?;
+        Ok(())
+    }
+
+    fn name(&self) -> String {
+        savepoint_name(self.depth)
+    }
+}
+
+fn savepoint_name(depth: u32) -> String {
+    format!("odbc_api_savepoint_{depth}")
+}
+
+impl Drop for Savepoint<'_, '_> {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        if let Err(e) = self.rollback_to_savepoint() {
+            error!("Rolling back to savepoint failed: {e}");
+        }
+    }
+}
+
+/// SQL syntax used to create, release and roll back to savepoints.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SavepointDialect {
+    /// `SAVEPOINT`, `RELEASE SAVEPOINT` and `ROLLBACK TO SAVEPOINT`
+    Standard,
+    /// `SAVE TRANSACTION` and `ROLLBACK TRANSACTION`
+    MsSql,
+}
+
+impl SavepointDialect {
+    /// Transaction support is a capability reported by `SQLGetInfo`. The savepoint syntax is not,
+    /// neither directly nor via one of the information types in [`crate::ConnectionInfo`]. So we
+    /// fall back to sniffing the name of the database management system and assume the standard
+    /// syntax for anything we do not know better about.
+    fn detect(connection: &Connection<'_>) -> Result<Self, Error> {
+        if !connection.supports_transactions()? {
+            return Err(Error::SavepointsNotSupported);
+        }
+        let dialect = if connection.database_management_system_name()? == "Microsoft SQL Server" {
+            SavepointDialect::MsSql
+        } else {
+            SavepointDialect::Standard
+        };
+        Ok(dialect)
+    }
+}
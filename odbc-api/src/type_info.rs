@@ -0,0 +1,144 @@
+use odbc_sys::SqlDataType;
+
+use crate::{
+    catalog::{integer, small_int, text},
+    handles::{AsStatementRef, Statement},
+    CatalogIter, CatalogRecord, CursorImpl, CursorRow, DataType, Error, Nullability,
+};
+
+/// Describes a data type supported by the data source, as reported by `SQLGetTypeInfo`. Allows
+/// applications to map their logical schema onto the concrete types offered by the database
+/// management system, e.g. in order to generate `CREATE TABLE` statements. See
+/// [`crate::Connection::type_info`].
+///
+/// See: <https://learn.microsoft.com/sql/odbc/reference/syntax/sqlgettypeinfo-function>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeInfo {
+    /// Data source dependent name of the type, e.g. `NVARCHAR` or `int8`. Use this name in `CREATE
+    /// TABLE` statements. `TYPE_NAME`.
+    pub type_name: String,
+    /// SQL data type this data source type maps to. Column size and decimal digits reflect the
+    /// maximum [`Self::column_size`] and [`Self::maximum_scale`] supported by the data source.
+    /// `DATA_TYPE`.
+    pub data_type: DataType,
+    /// Maximum column size the data source supports for this type, e.g. the maximum length of a
+    /// string or the maximum precision of a numeric type. `None` if not applicable.
+    /// `COLUMN_SIZE`.
+    pub column_size: Option<usize>,
+    /// Characters used to prefix a literal of this type, e.g. `'` for strings. `None` if not
+    /// applicable. `LITERAL_PREFIX`.
+    pub literal_prefix: Option<String>,
+    /// Characters used to terminate a literal of this type. `None` if not applicable.
+    /// `LITERAL_SUFFIX`.
+    pub literal_suffix: Option<String>,
+    /// Comma separated list of the keywords of the parameters the type accepts in a type
+    /// definition, e.g. `max length` for `VARCHAR` or `precision,scale` for `DECIMAL`. `None` if
+    /// the type does not take any parameters. `CREATE_PARAMS`.
+    pub create_params: Option<String>,
+    /// Whether the type accepts `NULL` values. `NULLABLE`.
+    pub nullable: Nullability,
+    /// `true` if the type is a character type which is case sensitive in collations and
+    /// comparisons. `CASE_SENSITIVE`.
+    pub case_sensitive: bool,
+    /// How the type can be used in a `WHERE` clause. `None` if the driver reports a value not
+    /// defined by the ODBC standard. `SEARCHABLE`.
+    pub searchable: Option<Searchable>,
+    /// `Some(true)` if the type is unsigned. `None` if not applicable. `UNSIGNED_ATTRIBUTE`.
+    pub unsigned: Option<bool>,
+    /// `true` if the type has a predefined fixed precision and scale, like a money type.
+    /// `FIXED_PREC_SCALE`.
+    pub fixed_prec_scale: bool,
+    /// `Some(true)` if the type is auto incrementing, i.e. the data source assigns values to
+    /// columns of this type when inserting rows. `None` if not applicable. `AUTO_UNIQUE_VALUE`.
+    pub auto_increment: Option<bool>,
+    /// Localized name of the type. `None` if not supported by the data source. `LOCAL_TYPE_NAME`.
+    pub local_type_name: Option<String>,
+    /// Minimum scale of the type. `None` if not applicable. `MINIMUM_SCALE`.
+    pub minimum_scale: Option<i16>,
+    /// Maximum scale of the type. `None` if not applicable. `MAXIMUM_SCALE`.
+    pub maximum_scale: Option<i16>,
+}
+
+impl TypeInfo {
+    /// Executes `SQLGetTypeInfo` on `statement` and reads the entire result set.
+    pub(crate) fn fetch(
+        mut statement: impl AsStatementRef,
+        data_type: Option<SqlDataType>,
+    ) -> Result<Vec<Self>, Error> {
+        let mut stmt = statement.as_stmt_ref();
+        // `SQL_ALL_TYPES` shares its value with `SQL_UNKNOWN_TYPE`.
+        stmt.type_info(data_type.unwrap_or(SqlDataType::UNKNOWN_TYPE))
+            .into_result(&stmt)?;
+        // Safe: `statement` is in Cursor state.
+        let cursor = unsafe { CursorImpl::new(statement) };
+        CatalogIter::new(cursor)?.collect()
+    }
+}
+
+impl CatalogRecord for TypeInfo {
+    fn read(row: &mut CursorRow<'_>, _num_cols: u16) -> Result<Self, Error> {
+        let type_name = text(row, 1)?.unwrap_or_default();
+        let sql_data_type = small_int(row, 2)?.unwrap_or(0);
+        let column_size = integer(row, 3)?.and_then(|size| size.try_into().ok());
+        let literal_prefix = text(row, 4)?;
+        let literal_suffix = text(row, 5)?;
+        let create_params = text(row, 6)?;
+        let nullable = small_int(row, 7)?.unwrap_or(2);
+        let case_sensitive = small_int(row, 8)?;
+        let searchable = small_int(row, 9)?;
+        let unsigned = small_int(row, 10)?;
+        let fixed_prec_scale = small_int(row, 11)?;
+        let auto_increment = small_int(row, 12)?;
+        let local_type_name = text(row, 13)?;
+        let minimum_scale = small_int(row, 14)?;
+        let maximum_scale = small_int(row, 15)?;
+
+        Ok(TypeInfo {
+            type_name,
+            data_type: DataType::new(
+                SqlDataType(sql_data_type),
+                column_size.unwrap_or(0),
+                maximum_scale.unwrap_or(0),
+            ),
+            column_size,
+            literal_prefix,
+            literal_suffix,
+            create_params,
+            nullable: Nullability::new(odbc_sys::Nullability(nullable)),
+            case_sensitive: case_sensitive == Some(1),
+            searchable: searchable.and_then(Searchable::from_sql),
+            unsigned: unsigned.map(|unsigned| unsigned == 1),
+            fixed_prec_scale: fixed_prec_scale == Some(1),
+            auto_increment: auto_increment.map(|auto_increment| auto_increment == 1),
+            local_type_name,
+            minimum_scale,
+            maximum_scale,
+        })
+    }
+}
+
+/// How a data type can be used in a `WHERE` clause. `SEARCHABLE` column of `SQLGetTypeInfo`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Searchable {
+    /// `SQL_PRED_NONE`. The type can not be used in a `WHERE` clause.
+    None,
+    /// `SQL_PRED_CHAR`. The type can only be used with `LIKE` in a `WHERE` clause.
+    LikeOnly,
+    /// `SQL_PRED_BASIC`. The type can be used with all comparison operators except `LIKE` in a
+    /// `WHERE` clause.
+    AllExceptLike,
+    /// `SQL_SEARCHABLE`. The type can be used with any comparison operator in a `WHERE` clause.
+    All,
+}
+
+impl Searchable {
+    fn from_sql(value: i16) -> Option<Self> {
+        match value {
+            0 => Some(Searchable::None),
+            1 => Some(Searchable::LikeOnly),
+            2 => Some(Searchable::AllExceptLike),
+            3 => Some(Searchable::All),
+            _ => None,
+        }
+    }
+}
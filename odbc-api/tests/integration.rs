@@ -11,8 +11,8 @@ use connection_strings::{
     MARIADB_CONNECTION, MSSQL_CONNECTION, POSTGRES_CONNECTION, SQLITE_3_CONNECTION,
 };
 
-#[cfg(feature = "derive")]
-use odbc_api::Fetch;
+#[cfg(feature = "futures")]
+use futures_util::TryStreamExt;
 use odbc_api::{
     buffers::{
         BufferDesc, ColumnarAnyBuffer, ColumnarBuffer, Indicator, Item, RowVec, TextColumn,
@@ -24,10 +24,15 @@ use odbc_api::{
         Blob, BlobRead, BlobSlice, InputParameter, VarBinaryArray, VarCharArray, VarCharSlice,
         VarCharSliceMut, VarWCharArray, WithDataType,
     },
-    sys, Bit, ColumnDescription, ConcurrentBlockCursor, Connection, ConnectionOptions, Cursor,
-    DataType, Error, InOut, IntoParameter, Narrow, Nullability, Nullable, Out, Preallocated,
-    ResultSetMetadata, RowSetBuffer, TruncationInfo, U16Str, U16String,
+    sys, Bit, ColumnDescription, ColumnInfo, Concurrency, ConcurrentBlockCursor, Connection,
+    ConnectionOptions, ConnectionPool, Cursor, CursorType, DataType, Error, InOut, IntoParameter,
+    IsolationLevel, LockType, Narrow, Nullability, Nullable, Out, ParamStatus, PoolOptions,
+    Preallocated, ProcedureColumnRow, ResultSetMetadata, RowIdScope, RowIdentifierType,
+    RowSetBuffer, RowStatus, SetPosOperation, StatementOptions, StatisticsRow, TruncationInfo,
+    U16Str, U16String,
 };
+#[cfg(feature = "derive")]
+use odbc_api::{Fetch, FromRow, Insert};
 
 use std::{
     ffi::CString,
@@ -36,7 +41,7 @@ use std::{
     num::NonZeroUsize,
     ptr::null_mut,
     str, thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 const MSSQL: &Profile = &Profile {
@@ -134,6 +139,73 @@ fn set_packet_size(profile: &Profile, expected_packet_size: u32) {
     assert_eq!(expected_packet_size, actual_packet_size)
 }
 
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn set_isolation_level_at_connect_time(profile: &Profile) {
+    let conn = ENV
+        .connect_with_connection_string(
+            profile.connection_string,
+            ConnectionOptions {
+                isolation_level: Some(IsolationLevel::Serializable),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    assert_eq!(
+        Some(IsolationLevel::Serializable),
+        conn.isolation_level().unwrap()
+    );
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn set_isolation_level_on_open_connection(profile: &Profile) {
+    let conn = profile.connection().unwrap();
+
+    conn.set_isolation_level(IsolationLevel::RepeatableRead)
+        .unwrap();
+
+    assert_eq!(
+        Some(IsolationLevel::RepeatableRead),
+        conn.isolation_level().unwrap()
+    );
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn supported_isolation_levels(profile: &Profile) {
+    let conn = profile.connection().unwrap();
+
+    let levels = conn.supported_isolation_levels().unwrap();
+
+    assert!(levels.contains(&IsolationLevel::ReadCommitted));
+    assert!(levels.contains(&IsolationLevel::Serializable));
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn read_only_access_mode(profile: &Profile) {
+    let conn = ENV
+        .connect_with_connection_string(
+            profile.connection_string,
+            ConnectionOptions {
+                read_only: Some(true),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert!(conn.is_read_only().unwrap());
+
+    conn.set_read_only(false).unwrap();
+
+    assert!(!conn.is_read_only().unwrap());
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 fn describe_columns(profile: &Profile) {
     let table_name = table_name!();
@@ -2077,6 +2149,150 @@ fn bulk_insert_with_multiple_batches(profile: &Profile) {
     assert_eq!(expected, actual);
 }
 
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn bulk_insert_with_report(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+    let prepared = conn.prepare(&table.sql_insert()).unwrap();
+    let desc = BufferDesc::I32 { nullable: false };
+    let mut inserter = prepared.into_column_inserter(3, [desc]).unwrap();
+    inserter.set_num_rows(3);
+    inserter
+        .column_mut(0)
+        .as_slice::<i32>()
+        .unwrap()
+        .copy_from_slice(&[1, 2, 3]);
+
+    // When
+    let report = inserter.execute_with_report().unwrap();
+
+    // Then
+    assert_eq!(3, report.params_processed);
+    assert_eq!(vec![ParamStatus::Success; 3], report.statuses);
+    assert!(report.failed_rows.is_empty());
+    assert_eq!("1\n2\n3", table.content_as_string(&conn));
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn row_wise_bulk_insert(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER", "VARCHAR(50)"])
+        .build(profile)
+        .unwrap();
+    let mut prepared = conn.prepare(&table.sql_insert()).unwrap();
+    let mut rows = RowVec::<(i32, VarCharArray<50>)>::new(3);
+    rows.set_num_rows(2);
+    rows[0] = (1, VarCharArray::new(b"one"));
+    rows[1] = (2, VarCharArray::new(b"two"));
+
+    // When
+    prepared.execute(&rows).unwrap();
+    // Columnar bulk inserts with the same statement must still work afterwards
+    let desc = [
+        BufferDesc::I32 { nullable: false },
+        BufferDesc::Text { max_str_len: 50 },
+    ];
+    let mut inserter = prepared.column_inserter(1, desc).unwrap();
+    inserter.set_num_rows(1);
+    inserter.column_mut(0).as_slice::<i32>().unwrap()[0] = 3;
+    inserter
+        .column_mut(1)
+        .as_text_view()
+        .unwrap()
+        .set_cell(0, Some(b"three"));
+    inserter.execute().unwrap();
+
+    // Then
+    assert_eq!("1,one\n2,two\n3,three", table.content_as_string(&conn));
+}
+
+#[cfg(feature = "derive")]
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn row_wise_bulk_insert_using_custom_row(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER", "INTEGER"])
+        .build(profile)
+        .unwrap();
+    #[derive(Clone, Copy, Default, Fetch, Insert)]
+    struct MyRow {
+        a: i32,
+        b: Nullable<i32>,
+    }
+    let mut rows = RowVec::<MyRow>::new(2);
+    rows.set_num_rows(2);
+    rows[0] = MyRow {
+        a: 1,
+        b: Nullable::new(10),
+    };
+    rows[1] = MyRow {
+        a: 2,
+        b: Nullable::null(),
+    };
+
+    // When
+    conn.execute(&table.sql_insert(), &rows).unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let mut block_cursor = cursor.bind_buffer(RowVec::<MyRow>::new(10)).unwrap();
+    let batch = block_cursor.fetch().unwrap().unwrap();
+
+    // Then
+    assert_eq!(2, batch.num_rows());
+    assert_eq!(1, batch[0].a);
+    assert_eq!(Some(&10), batch[0].b.as_opt());
+    assert_eq!(2, batch[1].a);
+    assert_eq!(None, batch[1].b.as_opt());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn bulk_insert_report_lists_failed_rows(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER NOT NULL"])
+        .build(profile)
+        .unwrap();
+    let prepared = conn.prepare(&table.sql_insert()).unwrap();
+    let desc = BufferDesc::I32 { nullable: true };
+    let mut inserter = prepared.into_column_inserter(3, [desc]).unwrap();
+    inserter.set_num_rows(3);
+    inserter
+        .column_mut(0)
+        .as_nullable_slice::<i32>()
+        .unwrap()
+        .write([Some(1), None, Some(3)].into_iter());
+
+    // When
+    let report = inserter.execute_with_report().unwrap();
+
+    // Then
+    assert_eq!(3, report.params_processed);
+    assert_eq!(ParamStatus::Error, report.statuses[1]);
+    assert_eq!(1, report.failed_rows.len());
+    assert_eq!(1, report.failed_rows[0].row_index);
+    assert!(!report.failed_rows[0].diagnostics.is_empty());
+    assert_eq!("1\n3", table.content_as_string(&conn));
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
@@ -2099,6 +2315,108 @@ fn send_connection(profile: &Profile) {
     assert_eq!("1\n2\n3", actual)
 }
 
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn connection_pool_reuses_connections(profile: &Profile) {
+    // Given a pool which may at most hold two connections
+    let pool = ConnectionPool::with_environment(
+        &ENV,
+        profile.connection_string,
+        ConnectionOptions::default(),
+        PoolOptions {
+            max_size: 2,
+            min_size: 1,
+            ..PoolOptions::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(1, pool.num_open());
+
+    // When using it from more threads, than it has connections
+    thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                let conn = pool.get().unwrap();
+                let cursor = conn.execute("SELECT 42", ()).unwrap().unwrap();
+                assert_eq!("42", cursor_to_string(cursor));
+            });
+        }
+    });
+
+    // Then
+    assert!(pool.num_open() <= 2);
+    assert_eq!(pool.num_open(), pool.num_idle());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn connection_pool_rolls_back_open_transaction_on_return(profile: &Profile) {
+    // Given a table and a pool with a single connection
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+    let pool = ConnectionPool::with_environment(
+        &ENV,
+        profile.connection_string,
+        ConnectionOptions::default(),
+        PoolOptions {
+            max_size: 1,
+            ..PoolOptions::default()
+        },
+    )
+    .unwrap();
+
+    // When returning a connection with an uncommitted insert
+    {
+        let pooled = pool.get().unwrap();
+        pooled.set_autocommit(false).unwrap();
+        pooled
+            .execute(&format!("INSERT INTO {table_name} (a) VALUES (5)"), ())
+            .unwrap();
+    }
+    // and reusing the connection in autocommit mode
+    let pooled = pool.get().unwrap();
+    pooled
+        .execute(&format!("INSERT INTO {table_name} (a) VALUES (42)"), ())
+        .unwrap();
+    drop(pooled);
+
+    // Then only the second insert is persisted
+    assert_eq!("42", table.content_as_string(&conn));
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn connection_pool_timeout(profile: &Profile) {
+    // Given an exhausted pool
+    let pool = ConnectionPool::with_environment(
+        &ENV,
+        profile.connection_string,
+        ConnectionOptions::default(),
+        PoolOptions {
+            max_size: 1,
+            checkout_timeout: Some(Duration::from_millis(50)),
+            ..PoolOptions::default()
+        },
+    )
+    .unwrap();
+    let _checked_out = pool.get().unwrap();
+
+    // When
+    let result = pool.get();
+
+    // Then
+    assert!(matches!(result, Err(Error::ConnectionPoolTimeout { .. })));
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
@@ -2369,67 +2687,386 @@ fn manual_commit_mode(profile: &Profile) {
     conn.commit().unwrap();
 }
 
-/// This test checks the behaviour if a connections goes out of scope with a transaction still
-/// open.
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn unfinished_transaction(profile: &Profile) {
-    let conn = profile
-        .setup_empty_table("UnfinishedTransaction", &["INTEGER"])
-        .unwrap();
-
-    // Manual commit mode needs to be explicitly enabled, since autocommit mode is default.
-    conn.set_autocommit(false).unwrap();
-
-    // Insert a value into the table.
-    conn.execute("INSERT INTO UnfinishedTransaction (a) VALUES (5);", ())
-        .unwrap();
-}
-
-/// Test behavior of strings with interior nul
-#[test_case(MSSQL, "a\0b"; "Microsoft SQL Server")]
-#[test_case(MARIADB, "a\0b"; "Maria DB")]
-#[test_case(SQLITE_3, "a"; "SQLite 3")]
-#[test_case(POSTGRES, "a"; "PostgreSQL")]
-fn interior_nul(profile: &Profile, expected: &str) {
-    let conn = profile
-        .setup_empty_table("InteriorNul", &["VARCHAR(10)"])
+fn statement_options_max_rows(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .values_by_column(&[&[Some("1"), Some("2"), Some("3")]])
+        .build(profile)
         .unwrap();
+    let query = format!("SELECT a FROM {table_name} ORDER BY id");
+    let options = StatementOptions {
+        max_rows: Some(2),
+        ..StatementOptions::default()
+    };
 
-    conn.execute(
-        "INSERT INTO InteriorNul (a) VALUES (?);",
-        &"a\0b".into_parameter(),
-    )
-    .unwrap();
+    // When
     let cursor = conn
-        .execute("SELECT A FROM InteriorNul;", ())
+        .execute_with_options(&query, (), options)
         .unwrap()
         .unwrap();
-    let actual = cursor_to_string(cursor);
-    assert_eq!(expected, actual);
+    let from_execute = cursor_to_string(cursor);
+    let mut prepared = conn.prepare(&query).unwrap();
+    prepared.set_options(options).unwrap();
+    let from_prepared = cursor_to_string(prepared.execute(()).unwrap().unwrap());
+    let mut preallocated = conn.preallocate().unwrap();
+    preallocated.set_options(options).unwrap();
+    let from_preallocated = cursor_to_string(preallocated.execute(&query, ()).unwrap().unwrap());
+
+    // Then
+    assert_eq!("1\n2", from_execute);
+    assert_eq!("1\n2", from_prepared);
+    assert_eq!("1\n2", from_preallocated);
 }
 
-/// Use get_data to retrieve an integer
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn get_data_int(profile: &Profile) {
+fn cursor_options_are_rejected_for_prepared_statement(profile: &Profile) {
+    // Given
+    let conn = profile.connection().unwrap();
+    let mut prepared = conn.prepare("SELECT 42").unwrap();
+    let options = StatementOptions {
+        cursor_type: Some(CursorType::Static),
+        ..StatementOptions::default()
+    };
+
+    // When
+    let result = prepared.set_options(options);
+
+    // Then
+    assert!(matches!(result, Err(Error::CursorOptionsAfterPrepare)));
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn scrollable_cursor(profile: &Profile) {
+    // Given
     let table_name = table_name!();
-    let (conn, table) = Given::new(&table_name)
+    let (conn, _table) = Given::new(&table_name)
         .column_types(&["INTEGER"])
+        .values_by_column(&[&[Some("1"), Some("2"), Some("3"), Some("4")]])
         .build(profile)
         .unwrap();
-    conn.execute(
-        &format!("INSERT INTO {table_name} (a) VALUES (42),(NULL)"),
-        (),
-    )
-    .unwrap();
-    let sql = table.sql_all_ordered_by_id();
+    let query = format!("SELECT a FROM {table_name} ORDER BY id");
+    let options = StatementOptions {
+        cursor_type: Some(CursorType::Static),
+        ..StatementOptions::default()
+    };
 
-    let mut cursor = conn.execute(&sql, ()).unwrap().unwrap();
+    // When
+    let mut cursor = conn
+        .execute_with_options(&query, (), options)
+        .unwrap()
+        .unwrap();
+    let buffer = TextRowSet::for_cursor(1, &mut cursor, Some(10)).unwrap();
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let value = |batch: Option<&TextRowSet>| {
+        batch.map(|batch| batch.at_as_str(0, 0).unwrap().unwrap().to_owned())
+    };
+    let last = value(cursor.fetch_last().unwrap());
+    let prior = value(cursor.fetch_prior().unwrap());
+    let first = value(cursor.fetch_first().unwrap());
+    let relative = value(cursor.fetch_relative(2).unwrap());
+    let absolute = value(cursor.fetch_absolute(2).unwrap());
+    let from_end = value(cursor.fetch_absolute(-1).unwrap());
+    let after_end = value(cursor.fetch_absolute(5).unwrap());
+    let prior_after_end = value(cursor.fetch_prior().unwrap());
+
+    // Then
+    assert_eq!(Some("4"), last.as_deref());
+    assert_eq!(Some("3"), prior.as_deref());
+    assert_eq!(Some("1"), first.as_deref());
+    assert_eq!(Some("3"), relative.as_deref());
+    assert_eq!(Some("2"), absolute.as_deref());
+    assert_eq!(Some("4"), from_end.as_deref());
+    assert_eq!(None, after_end);
+    // Moving backwards from after the end of the result set yields the last row
+    assert_eq!(Some("4"), prior_after_end.as_deref());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn positioned_update_delete_and_add(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .values_by_column(&[&[Some("1"), Some("2"), Some("3")]])
+        .build(profile)
+        .unwrap();
+    let options = StatementOptions {
+        cursor_type: Some(CursorType::KeysetDriven),
+        concurrency: Some(Concurrency::Lock),
+        ..StatementOptions::default()
+    };
+    let query = format!("SELECT a FROM {table_name}");
+
+    // When
+    let cursor = conn
+        .execute_with_options(&query, (), options)
+        .unwrap()
+        .unwrap();
+    let buffer = ColumnarAnyBuffer::from_descs(3, [BufferDesc::I32 { nullable: false }]);
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    cursor.fetch().unwrap().unwrap();
+    cursor.column_mut(0).as_slice::<i32>().unwrap()[1] = 42;
+    cursor
+        .set_pos(2, SetPosOperation::Update, LockType::NoChange)
+        .unwrap();
+    cursor
+        .set_pos(3, SetPosOperation::Delete, LockType::NoChange)
+        .unwrap();
+    cursor.column_mut(0).as_slice::<i32>().unwrap()[0] = 4;
+    cursor.bulk_add(1).unwrap();
+    drop(cursor);
+
+    // Then
+    let actual = table.content_as_string(&conn);
+    assert_eq!("1\n42\n4", actual);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn row_status_of_fetched_rows(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .values_by_column(&[&[Some("1"), Some("2"), Some("3")]])
+        .build(profile)
+        .unwrap();
+    let query = format!("SELECT a FROM {table_name} ORDER BY id");
+
+    // When
+    let cursor = conn.execute(&query, ()).unwrap().unwrap();
+    let buffer = ColumnarAnyBuffer::from_descs(2, [BufferDesc::I32 { nullable: true }]);
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+    let first_batch = [batch.row_status(0), batch.row_status(1)];
+    let batch = cursor.fetch().unwrap().unwrap();
+    let second_batch = [batch.row_status(0)];
+    let (cursor, _buffer) = cursor.unbind().unwrap();
+    drop(cursor);
+    let cursor = conn.execute(&query, ()).unwrap().unwrap();
+    let mut cursor = cursor.bind_buffer(RowVec::<(i32,)>::new(3)).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+    let row_vec = [
+        batch.row_status(0),
+        batch.row_status(1),
+        batch.row_status(2),
+    ];
+
+    // Then
+    assert_eq!([RowStatus::Success, RowStatus::Success], first_batch);
+    assert_eq!([RowStatus::Success], second_batch);
+    assert_eq!([RowStatus::Success; 3], row_vec);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn typed_access_to_row_fields(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["INTEGER", "VARCHAR(20)"])
+        .values_by_column(&[&[Some("42"), None], &[Some("Hello"), None]])
+        .build(profile)
+        .unwrap();
+    let query = format!("SELECT a, b FROM {table_name} ORDER BY id");
+
+    // When
+    let mut cursor = conn.execute(&query, ()).unwrap().unwrap();
+    let mut row = cursor.next_row().unwrap().unwrap();
+    let first_int = row.get::<i32>(1).unwrap();
+    let first_text = row.get::<String>(2).unwrap();
+    let mut row = cursor.next_row().unwrap().unwrap();
+    let second_int = row.get::<Option<i32>>(1).unwrap();
+    let second_text = row.get::<String>(2);
+
+    // Then
+    assert_eq!(42, first_int);
+    assert_eq!("Hello", first_text);
+    assert_eq!(None, second_int);
+    assert!(matches!(
+        second_text,
+        Err(Error::UnexpectedNull { column: 2, .. })
+    ));
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn statement_options_query_timeout(profile: &Profile) {
+    // Given
+    let conn = profile.connection().unwrap();
+    let options = StatementOptions {
+        query_timeout_sec: Some(1),
+        ..StatementOptions::default()
+    };
+
+    // When
+    let result = conn.execute_with_options("WAITFOR DELAY '00:00:05'", (), options);
+
+    // Then
+    assert!(matches!(result, Err(Error::Timeout { .. })));
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn cancel_blocking_execution_from_other_thread(profile: &Profile) {
+    // Given
+    let conn = profile.connection().unwrap();
+    let mut statement = conn.preallocate().unwrap();
+    let cancel_handle = statement.cancel_handle();
+
+    // When
+    let result = thread::scope(|s| {
+        s.spawn(move || {
+            thread::sleep(Duration::from_millis(500));
+            // Safety: `statement` outlives the scope.
+            unsafe { cancel_handle.cancel() }.unwrap();
+        });
+        statement
+            .execute("WAITFOR DELAY '00:00:10'", ())
+            .map(|_| ())
+    });
+
+    // Then
+    assert!(matches!(result, Err(Error::Cancelled { .. })));
+    // Statement can be reused after cancellation
+    let cursor = statement.execute("SELECT 42", ()).unwrap().unwrap();
+    assert_eq!("42", cursor_to_string(cursor));
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn transaction_rolls_back_on_drop(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+    let insert = format!("INSERT INTO {table_name} (a) VALUES (?)");
+
+    // When
+    {
+        let transaction = conn.begin_transaction().unwrap();
+        transaction.execute(&insert, &5).unwrap();
+        // Guard goes out of scope without committing
+    }
+    let transaction = conn.begin_transaction().unwrap();
+    transaction.execute(&insert, &42).unwrap();
+    transaction.commit().unwrap();
+
+    // Then
+    assert!(conn.is_autocommit().unwrap());
+    assert_eq!("42", table.content_as_string(&conn));
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn transaction_savepoints(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+    let insert = format!("INSERT INTO {table_name} (a) VALUES (?)");
+
+    // When
+    let mut transaction = conn.begin_transaction().unwrap();
+    transaction.execute(&insert, &1).unwrap();
+    {
+        let mut outer = transaction.savepoint().unwrap();
+        outer.execute(&insert, &2).unwrap();
+        {
+            let inner = outer.savepoint().unwrap();
+            inner.execute(&insert, &3).unwrap();
+            // Roll back to inner savepoint on drop
+        }
+        outer.release().unwrap();
+    }
+    transaction.commit().unwrap();
+
+    // Then
+    assert_eq!("1\n2", table.content_as_string(&conn));
+}
+
+/// This test checks the behaviour if a connections goes out of scope with a transaction still
+/// open.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn unfinished_transaction(profile: &Profile) {
+    let conn = profile
+        .setup_empty_table("UnfinishedTransaction", &["INTEGER"])
+        .unwrap();
+
+    // Manual commit mode needs to be explicitly enabled, since autocommit mode is default.
+    conn.set_autocommit(false).unwrap();
+
+    // Insert a value into the table.
+    conn.execute("INSERT INTO UnfinishedTransaction (a) VALUES (5);", ())
+        .unwrap();
+}
+
+/// Test behavior of strings with interior nul
+#[test_case(MSSQL, "a\0b"; "Microsoft SQL Server")]
+#[test_case(MARIADB, "a\0b"; "Maria DB")]
+#[test_case(SQLITE_3, "a"; "SQLite 3")]
+#[test_case(POSTGRES, "a"; "PostgreSQL")]
+fn interior_nul(profile: &Profile, expected: &str) {
+    let conn = profile
+        .setup_empty_table("InteriorNul", &["VARCHAR(10)"])
+        .unwrap();
+
+    conn.execute(
+        "INSERT INTO InteriorNul (a) VALUES (?);",
+        &"a\0b".into_parameter(),
+    )
+    .unwrap();
+    let cursor = conn
+        .execute("SELECT A FROM InteriorNul;", ())
+        .unwrap()
+        .unwrap();
+    let actual = cursor_to_string(cursor);
+    assert_eq!(expected, actual);
+}
+
+/// Use get_data to retrieve an integer
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn get_data_int(profile: &Profile) {
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+    conn.execute(
+        &format!("INSERT INTO {table_name} (a) VALUES (42),(NULL)"),
+        (),
+    )
+    .unwrap();
+    let sql = table.sql_all_ordered_by_id();
+
+    let mut cursor = conn.execute(&sql, ()).unwrap().unwrap();
 
     let mut actual = Nullable::<i32>::null();
     // First value is 42
@@ -3351,6 +3988,28 @@ fn database_management_system_name(profile: &Profile, expected_name: &'static st
     assert_eq!(expected_name, actual_name);
 }
 
+#[test_case(MSSQL, "Microsoft SQL Server", "\""; "Microsoft SQL Server")]
+#[test_case(MARIADB, "MariaDB", "`"; "Maria DB")]
+#[test_case(SQLITE_3, "SQLite", "\""; "SQLite 3")]
+#[test_case(POSTGRES, "PostgreSQL", "\""; "PostgreSQL")]
+fn connection_info(profile: &Profile, expected_dbms_name: &str, expected_quote_char: &str) {
+    let conn = profile.connection().unwrap();
+
+    let info = conn.info().unwrap();
+
+    assert_eq!(expected_dbms_name, info.dbms_name);
+    assert_eq!(
+        Some(expected_quote_char),
+        info.identifier_quote_char.as_deref()
+    );
+    assert!(info.supports_quoted_identifiers());
+    assert!(!info.dbms_version.is_empty());
+    assert!(!info.driver_name.is_empty());
+    assert!(!info.driver_version.is_empty());
+    assert!(info.driver_odbc_version.starts_with("03."));
+    assert!(info.odbc_version.starts_with("03."));
+}
+
 // Check the max name length for the catalogs, schemas, tables, and columns.
 #[test_case(MSSQL, 128, 128, 128, 128; "Microsoft SQL Server")]
 #[test_case(MARIADB, 256, 0, 256, 255; "Maria DB")]
@@ -4042,53 +4701,572 @@ fn list_foreign_keys_prealloc(profile: &Profile) {
     assert_eq!(batch.num_rows(), 1);
 }
 
-// The two failing drivers confuse buffer and character lengths with each other. It could not be
-// worked around by allocating larger buffers.
-// #[test_case(MSSQL; "Microsoft SQL Server")]
-// #[test_case(MARIADB; "Maria DB")]
-
-// SQLite just does not recognize the last letter
-// #[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn describe_column_name_with_umlaut(profile: &Profile) {
+fn list_table_infos(profile: &Profile) {
+    // Given
     let table_name = table_name!();
-    // Given a table with an umlaut in a column name
-    let (conn, _table) = Given::new(&table_name)
-        .column_types(&["INTEGER"])
-        .column_names(&["hällo𐐏"])
-        .build(profile)
+    let conn = profile
+        .setup_empty_table(&table_name, &["INTEGER"])
         .unwrap();
 
-    // When executing a query with a result set containing that column and describing that column
-    let mut result_set = conn
-        .execute(&format!("SELECT hällo𐐏 FROM {table_name}"), ())
+    // When
+    let tables: Vec<_> = conn
+        .table_infos("", "", &table_name, "")
         .unwrap()
+        .collect::<Result<_, _>>()
         .unwrap();
-    let mut desc = ColumnDescription::default();
-    result_set.describe_col(1, &mut desc).unwrap();
-    let column_name = desc.name_to_string().unwrap();
 
     // Then
-    assert_eq!("hällo𐐏", column_name);
+    assert_eq!(1, tables.len());
+    assert_eq!(table_name, tables[0].name);
+    assert_eq!("table", tables[0].table_type.to_lowercase());
 }
 
 #[test_case(MSSQL; "Microsoft SQL Server")]
-// #[test_case(MARIADB; "Maria DB")] Only allows one SQL Statement
-// #[test_case(SQLITE_3; "SQLite 3")] Only allows one SQL Statement
-#[test_case(POSTGRES; "PostgreSQL")]
-fn execute_two_select_statements(profile: &Profile) {
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+// #[test_case(POSTGRES; "PostgreSQL")] Fails in linux, like `list_columns`
+fn list_column_infos(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
     let conn = profile.connection().unwrap();
+    conn.execute(&format!("DROP TABLE IF EXISTS {table_name};"), ())
+        .unwrap();
+    conn.execute(
+        &format!("CREATE TABLE {table_name} (a INTEGER NOT NULL, b VARCHAR(10));"),
+        (),
+    )
+    .unwrap();
 
-    let cursor = conn
-        .execute("SELECT 1 AS A; SELECT 2 AS B;", ())
+    // When
+    let mut stmt = conn.preallocate().unwrap();
+    let columns: Vec<ColumnInfo> = stmt
+        .column_infos("", "", &table_name, "")
         .unwrap()
+        .collect::<Result<_, _>>()
         .unwrap();
 
-    let maybe_cursor = cursor.more_results().unwrap();
-    assert!(maybe_cursor.is_some());
-    let cursor = maybe_cursor.unwrap();
-    let maybe_cursor = cursor.more_results().unwrap();
-    assert!(maybe_cursor.is_none());
+    // Then
+    let actual: Vec<_> = columns
+        .iter()
+        .map(|column| {
+            (
+                column.name.as_str(),
+                column.data_type,
+                column.nullability,
+                column.ordinal_position,
+            )
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            ("a", DataType::Integer, Nullability::NoNulls, Some(1)),
+            (
+                "b",
+                DataType::Varchar {
+                    length: NonZeroUsize::new(10)
+                },
+                Nullability::Nullable,
+                Some(2)
+            )
+        ],
+        actual
+    );
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn list_foreign_key_infos(profile: &Profile) {
+    // Given other table references table
+    let pk_table_name = table_name!();
+    let fk_table_name = format!("other_{pk_table_name}");
+    let conn = profile.connection().unwrap();
+    conn.execute(&format!("DROP TABLE IF EXISTS {fk_table_name};"), ())
+        .unwrap();
+    conn.execute(&format!("DROP TABLE IF EXISTS {pk_table_name};"), ())
+        .unwrap();
+    conn.execute(
+        &format!("CREATE TABLE {pk_table_name} (id INTEGER, PRIMARY KEY(id));"),
+        (),
+    )
+    .unwrap();
+    conn.execute(
+        &format!(
+            "CREATE TABLE {fk_table_name} (ext_id INTEGER, FOREIGN KEY (ext_id) REFERENCES \
+            {pk_table_name}(id));"
+        ),
+        (),
+    )
+    .unwrap();
+
+    // When
+    let foreign_keys: Vec<_> = conn
+        .foreign_key_infos("", "", "", "", "", &fk_table_name)
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    // Then
+    assert_eq!(1, foreign_keys.len());
+    let foreign_key = &foreign_keys[0];
+    assert_eq!(pk_table_name, foreign_key.pk_table);
+    assert_eq!("id", foreign_key.pk_column);
+    assert_eq!(fk_table_name, foreign_key.fk_table);
+    assert_eq!("ext_id", foreign_key.fk_column);
+    assert_eq!(1, foreign_key.key_seq);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn describe_table(profile: &Profile) {
+    // Given a table with a primary key, a foreign key and a unique index
+    let table_name = table_name!();
+    let parent_table_name = format!("parent_{table_name}");
+    let conn = profile.connection().unwrap();
+    conn.execute(&format!("DROP TABLE IF EXISTS {table_name};"), ())
+        .unwrap();
+    conn.execute(&format!("DROP TABLE IF EXISTS {parent_table_name};"), ())
+        .unwrap();
+    conn.execute(
+        &format!("CREATE TABLE {parent_table_name} (id INTEGER, PRIMARY KEY(id));"),
+        (),
+    )
+    .unwrap();
+    conn.execute(
+        &format!(
+            "CREATE TABLE {table_name} (id INTEGER NOT NULL, parent_id INTEGER, PRIMARY KEY(id), \
+            FOREIGN KEY (parent_id) REFERENCES {parent_table_name}(id));"
+        ),
+        (),
+    )
+    .unwrap();
+    conn.execute(
+        &format!("CREATE UNIQUE INDEX {table_name}_idx ON {table_name} (parent_id);"),
+        (),
+    )
+    .unwrap();
+
+    // When
+    let schema = conn.describe_table("", "", &table_name).unwrap().unwrap();
+
+    // Then
+    assert_eq!(table_name, schema.name);
+    let column_names: Vec<_> = schema.columns.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(vec!["id", "parent_id"], column_names);
+    assert_eq!(Nullability::NoNulls, schema.columns[0].nullability);
+    assert_eq!(vec!["id"], schema.primary_key.unwrap().columns);
+    assert_eq!(1, schema.foreign_keys.len());
+    assert_eq!(parent_table_name, schema.foreign_keys[0].referenced_table);
+    assert_eq!(vec!["parent_id"], schema.foreign_keys[0].columns);
+    assert!(schema
+        .indexes
+        .iter()
+        .any(|index| index.name == format!("{table_name}_idx")
+            && index.unique
+            && index.columns == ["parent_id"]));
+    // Describing the same table twice yields the same result
+    assert_eq!(
+        conn.describe_table("", "", &table_name).unwrap(),
+        conn.describe_table("", "", &table_name).unwrap()
+    );
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn describe_non_existing_table(profile: &Profile) {
+    let conn = profile.connection().unwrap();
+
+    let schema = conn
+        .describe_table("", "", "DescribeNonExistingTable")
+        .unwrap();
+
+    assert!(schema.is_none());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn list_primary_keys(profile: &Profile) {
+    // Given a table with a composite primary key
+    let table_name = table_name!();
+    let conn = profile.connection().unwrap();
+    conn.execute(&format!("DROP TABLE IF EXISTS {table_name};"), ())
+        .unwrap();
+    conn.execute(
+        &format!("CREATE TABLE {table_name} (a INTEGER, b INTEGER, PRIMARY KEY(b, a));"),
+        (),
+    )
+    .unwrap();
+
+    // When
+    let mut cursor = conn.primary_keys("", "", &table_name).unwrap();
+    let buffer = TextRowSet::for_cursor(10, &mut cursor, Some(256)).unwrap();
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+
+    // Then TABLE_NAME, COLUMN_NAME and KEY_SEQ
+    assert_eq!(batch.num_rows(), 2);
+    let key_columns: Vec<_> = (0..batch.num_rows())
+        .map(|row_index| {
+            (
+                batch.at_as_str(2, row_index).unwrap().unwrap(),
+                batch.at_as_str(3, row_index).unwrap().unwrap(),
+                batch.at_as_str(4, row_index).unwrap().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            (table_name.as_str(), "b", "1"),
+            (table_name.as_str(), "a", "2")
+        ],
+        key_columns
+    );
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn list_primary_keys_prealloc(profile: &Profile) {
+    // Given a table with a composite primary key
+    let table_name = table_name!();
+    let conn = profile.connection().unwrap();
+    conn.execute(&format!("DROP TABLE IF EXISTS {table_name};"), ())
+        .unwrap();
+    conn.execute(
+        &format!("CREATE TABLE {table_name} (a INTEGER, b INTEGER, PRIMARY KEY(b, a));"),
+        (),
+    )
+    .unwrap();
+
+    // When
+    let mut stmt = conn.preallocate().unwrap();
+    let mut cursor = stmt.primary_keys("", "", &table_name).unwrap();
+    let buffer = TextRowSet::for_cursor(10, &mut cursor, Some(256)).unwrap();
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+
+    // Then TABLE_NAME, COLUMN_NAME and KEY_SEQ
+    assert_eq!(batch.num_rows(), 2);
+    let key_columns: Vec<_> = (0..batch.num_rows())
+        .map(|row_index| {
+            (
+                batch.at_as_str(2, row_index).unwrap().unwrap(),
+                batch.at_as_str(3, row_index).unwrap().unwrap(),
+                batch.at_as_str(4, row_index).unwrap().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            (table_name.as_str(), "b", "1"),
+            (table_name.as_str(), "a", "2")
+        ],
+        key_columns
+    );
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn list_statistics(profile: &Profile) {
+    // Given a table with a composite unique index
+    let table_name = table_name!();
+    let conn = profile.connection().unwrap();
+    conn.execute(&format!("DROP TABLE IF EXISTS {table_name};"), ())
+        .unwrap();
+    conn.execute(
+        &format!("CREATE TABLE {table_name} (a INTEGER, b INTEGER);"),
+        (),
+    )
+    .unwrap();
+    conn.execute(
+        &format!("CREATE UNIQUE INDEX {table_name}_idx ON {table_name} (b, a);"),
+        (),
+    )
+    .unwrap();
+
+    // When
+    let cursor = conn.statistics("", "", &table_name, true, false).unwrap();
+    let mut cursor = cursor
+        .bind_buffer(RowVec::<StatisticsRow>::new(10))
+        .unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+
+    // Then
+    let index_columns: Vec<_> = batch
+        .iter()
+        .filter(|row| !row.is_table_statistic())
+        .map(|row| {
+            (
+                row.index_name.as_str().unwrap().unwrap().to_owned(),
+                row.column_name.as_str().unwrap().unwrap().to_owned(),
+                row.ordinal_position.into_opt().unwrap(),
+                row.is_unique(),
+            )
+        })
+        .collect();
+    let index_name = format!("{table_name}_idx");
+    assert_eq!(
+        vec![
+            (index_name.clone(), "b".to_owned(), 1, Some(true)),
+            (index_name, "a".to_owned(), 2, Some(true))
+        ],
+        index_columns
+    );
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn list_statistics_prealloc(profile: &Profile) {
+    // Given a table with a unique index
+    let table_name = table_name!();
+    let conn = profile.connection().unwrap();
+    conn.execute(&format!("DROP TABLE IF EXISTS {table_name};"), ())
+        .unwrap();
+    conn.execute(
+        &format!("CREATE TABLE {table_name} (a INTEGER, b INTEGER);"),
+        (),
+    )
+    .unwrap();
+    conn.execute(
+        &format!("CREATE UNIQUE INDEX {table_name}_idx ON {table_name} (b);"),
+        (),
+    )
+    .unwrap();
+
+    // When
+    let mut stmt = conn.preallocate().unwrap();
+    let mut cursor = stmt.statistics("", "", &table_name, true, false).unwrap();
+    let buffer = TextRowSet::for_cursor(10, &mut cursor, Some(256)).unwrap();
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+
+    // Then INDEX_NAME and COLUMN_NAME of the rows which do not describe the table itself
+    let index_columns: Vec<_> = (0..batch.num_rows())
+        .filter(|&row_index| batch.at_as_str(6, row_index).unwrap() != Some("0"))
+        .map(|row_index| {
+            (
+                batch.at_as_str(5, row_index).unwrap().unwrap(),
+                batch.at_as_str(8, row_index).unwrap().unwrap(),
+            )
+        })
+        .collect();
+    let index_name = format!("{table_name}_idx");
+    assert_eq!(vec![(index_name.as_str(), "b")], index_columns);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn list_procedures(profile: &Profile) {
+    // Given a stored procedure
+    let conn = profile.connection().unwrap();
+    conn.execute("DROP PROCEDURE IF EXISTS TestListProcedures", ())
+        .unwrap();
+    conn.execute(
+        "CREATE PROCEDURE TestListProcedures @a INTEGER AS SELECT @a",
+        (),
+    )
+    .unwrap();
+
+    // When
+    let mut cursor = conn.procedures("", "", "TestListProcedures").unwrap();
+    let buffer = TextRowSet::for_cursor(10, &mut cursor, Some(256)).unwrap();
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+
+    // Then PROCEDURE_NAME. Microsoft SQL Server appends the procedure number.
+    assert_eq!(batch.num_rows(), 1);
+    assert_eq!(
+        "TestListProcedures;1",
+        batch.at_as_str(2, 0).unwrap().unwrap()
+    );
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn list_procedure_columns(profile: &Profile) {
+    // Given a stored procedure with an input and an output parameter
+    let conn = profile.connection().unwrap();
+    conn.execute("DROP PROCEDURE IF EXISTS TestListProcedureColumns", ())
+        .unwrap();
+    conn.execute(
+        "CREATE PROCEDURE TestListProcedureColumns @a INTEGER, @b VARCHAR(10) OUTPUT \
+        AS SELECT @b = 'Hello'",
+        (),
+    )
+    .unwrap();
+
+    // When
+    let mut stmt = conn.preallocate().unwrap();
+    let cursor = stmt
+        .procedure_columns("", "", "TestListProcedureColumns", "")
+        .unwrap();
+    let mut cursor = cursor
+        .bind_buffer(RowVec::<ProcedureColumnRow>::new(10))
+        .unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+
+    // Then the return value, followed by the parameters. Microsoft SQL Server reports output
+    // parameters as input / output parameters.
+    let parameters: Vec<_> = batch
+        .iter()
+        .map(|row| {
+            (
+                row.column_name.as_str().unwrap().unwrap().to_owned(),
+                row.ordinal_position,
+                row.is_input(),
+                row.is_output(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            ("@RETURN_VALUE".to_owned(), 0, false, true),
+            ("@a".to_owned(), 1, true, false),
+            ("@b".to_owned(), 2, true, true)
+        ],
+        parameters
+    );
+    assert_eq!(ProcedureColumnRow::PARAM_INPUT_OUTPUT, batch[2].column_type);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn list_special_columns(profile: &Profile) {
+    // Given a table with a primary key
+    let table_name = table_name!();
+    let conn = profile.connection().unwrap();
+    conn.execute(&format!("DROP TABLE IF EXISTS {table_name};"), ())
+        .unwrap();
+    conn.execute(
+        &format!("CREATE TABLE {table_name} (id INTEGER PRIMARY KEY, a INTEGER);"),
+        (),
+    )
+    .unwrap();
+
+    // When
+    let mut cursor = conn
+        .special_columns(
+            RowIdentifierType::BestRowId,
+            "",
+            "",
+            &table_name,
+            RowIdScope::CurrentRow,
+            false,
+        )
+        .unwrap();
+    let buffer = TextRowSet::for_cursor(10, &mut cursor, Some(256)).unwrap();
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+
+    // Then COLUMN_NAME
+    assert_eq!(batch.num_rows(), 1);
+    assert_eq!("id", batch.at_as_str(1, 0).unwrap().unwrap());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn list_table_and_column_privileges(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let conn = profile.connection().unwrap();
+    conn.execute(&format!("DROP TABLE IF EXISTS {table_name};"), ())
+        .unwrap();
+    conn.execute(&format!("CREATE TABLE {table_name} (a INTEGER);"), ())
+        .unwrap();
+
+    // When
+    let mut table_privileges = conn.table_privileges("", "", &table_name).unwrap();
+    let num_table_privilege_cols = table_privileges.num_result_cols().unwrap();
+    drop(table_privileges);
+    let mut stmt = conn.preallocate().unwrap();
+    let mut column_privileges = stmt.column_privileges("", "", &table_name, "a").unwrap();
+    let num_column_privilege_cols = column_privileges.num_result_cols().unwrap();
+
+    // Then the result sets have the columns specified by ODBC
+    assert_eq!(7, num_table_privilege_cols);
+    assert_eq!(8, num_column_privilege_cols);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn type_info(profile: &Profile) {
+    // Given
+    let conn = profile.connection().unwrap();
+
+    // When
+    let all_types = conn.type_info(None).unwrap();
+    let integer_types = conn.type_info(Some(SqlDataType::INTEGER)).unwrap();
+
+    // Then
+    assert!(!integer_types.is_empty());
+    assert!(all_types.len() > integer_types.len());
+    assert!(integer_types
+        .iter()
+        .all(|info| info.data_type == DataType::Integer && !info.type_name.is_empty()));
+}
+
+// The two failing drivers confuse buffer and character lengths with each other. It could not be
+// worked around by allocating larger buffers.
+// #[test_case(MSSQL; "Microsoft SQL Server")]
+// #[test_case(MARIADB; "Maria DB")]
+
+// SQLite just does not recognize the last letter
+// #[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn describe_column_name_with_umlaut(profile: &Profile) {
+    let table_name = table_name!();
+    // Given a table with an umlaut in a column name
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .column_names(&["hällo𐐏"])
+        .build(profile)
+        .unwrap();
+
+    // When executing a query with a result set containing that column and describing that column
+    let mut result_set = conn
+        .execute(&format!("SELECT hällo𐐏 FROM {table_name}"), ())
+        .unwrap()
+        .unwrap();
+    let mut desc = ColumnDescription::default();
+    result_set.describe_col(1, &mut desc).unwrap();
+    let column_name = desc.name_to_string().unwrap();
+
+    // Then
+    assert_eq!("hällo𐐏", column_name);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+// #[test_case(MARIADB; "Maria DB")] Only allows one SQL Statement
+// #[test_case(SQLITE_3; "SQLite 3")] Only allows one SQL Statement
+#[test_case(POSTGRES; "PostgreSQL")]
+fn execute_two_select_statements(profile: &Profile) {
+    let conn = profile.connection().unwrap();
+
+    let cursor = conn
+        .execute("SELECT 1 AS A; SELECT 2 AS B;", ())
+        .unwrap()
+        .unwrap();
+
+    let maybe_cursor = cursor.more_results().unwrap();
+    assert!(maybe_cursor.is_some());
+    let cursor = maybe_cursor.unwrap();
+    let maybe_cursor = cursor.more_results().unwrap();
+    assert!(maybe_cursor.is_none());
 }
 
 #[test_case(MSSQL; "Microsoft SQL Server")]
@@ -4687,6 +5865,218 @@ async fn async_bulk_fetch(profile: &Profile, expected_to_support_polling: bool)
     assert_eq!(expected_to_support_polling, used_polling);
 }
 
+/// Only Microsoft SQL Server supports polling, so only there the statement is still executing then
+/// the future is dropped.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[tokio::test]
+async fn dropping_polling_execution_cancels_statement(profile: &Profile) {
+    // Given a statement in polling mode
+    let conn = profile.connection().unwrap();
+    let mut statement = conn.preallocate().unwrap().into_polling().unwrap();
+    let sleep = || tokio::time::sleep(Duration::from_millis(10));
+    let start = Instant::now();
+
+    // When dropping the future of a long running query mid-execution
+    let timed_out = tokio::time::timeout(
+        Duration::from_millis(500),
+        statement.execute("WAITFOR DELAY '00:00:10'", (), sleep),
+    )
+    .await
+    .is_err();
+
+    // Then the statement is canceled rather than executed to completion, and can be reused
+    assert!(timed_out);
+    let has_cursor = statement
+        .execute("SELECT 42", (), sleep)
+        .await
+        .unwrap()
+        .is_some();
+    assert!(has_cursor);
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[tokio::test]
+async fn dropping_polling_execution_of_connection_cancels_statement(profile: &Profile) {
+    // Given
+    let conn = profile.connection().unwrap();
+    let sleep = || tokio::time::sleep(Duration::from_millis(10));
+    let start = Instant::now();
+
+    // When dropping the future of a long running query mid-execution
+    let result = tokio::time::timeout(
+        Duration::from_millis(500),
+        conn.execute_polling("WAITFOR DELAY '00:00:10'", (), sleep),
+    )
+    .await;
+
+    // Then the statement is canceled, freed without panicking and the connection is still usable.
+    assert!(result.is_err());
+    let cursor = conn.execute("SELECT 42", ()).unwrap().unwrap();
+    assert_eq!("42", cursor_to_string(cursor));
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+#[tokio::test]
+async fn polling_next_row_and_get_text(profile: &Profile) {
+    // Given a value larger than the default buffer size of 256 and a NULL
+    let table_name = table_name!();
+    let large = "a".repeat(1000);
+    let values: &[&[Option<&str>]] = &[&[Some(&large), None]];
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(1000)"])
+        .values_by_column(values)
+        .build(profile)
+        .unwrap();
+    let sleep = || tokio::time::sleep(Duration::from_millis(10));
+
+    // When
+    let mut cursor = conn
+        .execute_polling(&table.sql_all_ordered_by_id(), (), sleep)
+        .await
+        .unwrap()
+        .unwrap();
+    let mut texts = Vec::new();
+    let mut buf = Vec::new();
+    while let Some(mut row) = cursor.next_row(sleep).await.unwrap() {
+        let not_null = row.get_text(1, &mut buf, sleep).await.unwrap();
+        texts.push(not_null.then(|| String::from_utf8(buf.clone()).unwrap()));
+    }
+
+    // Then
+    assert_eq!(vec![Some(large.clone()), None], texts);
+}
+
+#[cfg(feature = "futures")]
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+#[tokio::test]
+async fn stream_batches_of_block_cursor_polling(profile: &Profile) {
+    // Given a result set spanning three batches of at most two rows
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .values_by_column(&[&[Some("1"), Some("2"), Some("3"), Some("4"), Some("5")]])
+        .build(profile)
+        .unwrap();
+    let sleep = || tokio::time::sleep(Duration::from_millis(10));
+    let cursor = conn
+        .execute_polling(&table.sql_all_ordered_by_id(), (), sleep)
+        .await
+        .unwrap()
+        .unwrap();
+    let buffer = ColumnarAnyBuffer::from_descs(2, [BufferDesc::I32 { nullable: false }]);
+
+    // When
+    let batches: Vec<Vec<i32>> = cursor
+        .bind_buffer(buffer)
+        .unwrap()
+        .into_stream(sleep)
+        .map_ok(|batch| batch.column(0).as_slice::<i32>().unwrap().to_vec())
+        .try_collect()
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(vec![vec![1, 2], vec![3, 4], vec![5]], batches);
+}
+
+#[cfg(feature = "futures")]
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+#[tokio::test]
+async fn stream_batches_of_block_cursor_polling_recycling_buffers(profile: &Profile) {
+    // Given a result set spanning three batches of at most two rows
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .values_by_column(&[&[Some("1"), Some("2"), Some("3"), Some("4"), Some("5")]])
+        .build(profile)
+        .unwrap();
+    let sleep = || tokio::time::sleep(Duration::from_millis(10));
+    let cursor = conn
+        .execute_polling(&table.sql_all_ordered_by_id(), (), sleep)
+        .await
+        .unwrap()
+        .unwrap();
+    let buffer = ColumnarAnyBuffer::from_descs(2, [BufferDesc::I32 { nullable: false }]);
+    let mut stream = cursor.bind_buffer(buffer).unwrap().into_stream(sleep);
+
+    // When handing each batch back to the stream after inspecting it
+    let mut batches = Vec::new();
+    while let Some(batch) = stream.try_next().await.unwrap() {
+        batches.push(batch.column(0).as_slice::<i32>().unwrap().to_vec());
+        stream.fill(batch).unwrap();
+    }
+    // and handing back a buffer of a different layout
+    let other_layout = ColumnarAnyBuffer::from_descs(2, [BufferDesc::I64 { nullable: false }]);
+    let result = stream.fill(other_layout);
+
+    // Then
+    assert_eq!(vec![vec![1, 2], vec![3, 4], vec![5]], batches);
+    assert!(matches!(result, Err(Error::BufferLayoutMismatch)));
+}
+
+/// Drivers without support for asynchronous connection functions should fall back to blocking.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+#[tokio::test]
+async fn connect_polling(profile: &Profile) {
+    // Given
+    let sleep = || tokio::time::sleep(Duration::from_millis(10));
+
+    // When
+    let conn = ENV
+        .connect_polling(
+            profile.connection_string,
+            ConnectionOptions::default(),
+            sleep,
+        )
+        .await
+        .unwrap();
+
+    // Then the connection can be used with blocking functions
+    let cursor = conn.execute("SELECT 42", ()).unwrap().unwrap();
+    assert_eq!("42", cursor_to_string(cursor));
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+#[tokio::test]
+async fn commit_and_rollback_polling(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (mut conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+    let insert = format!("INSERT INTO {table_name} (a) VALUES (?)");
+    let sleep = || tokio::time::sleep(Duration::from_millis(10));
+    conn.set_autocommit(false).unwrap();
+
+    // When
+    conn.execute(&insert, &42).unwrap();
+    conn.commit_polling(sleep).await.unwrap();
+    conn.execute(&insert, &5).unwrap();
+    conn.rollback_polling(sleep).await.unwrap();
+
+    // Then
+    conn.set_autocommit(true).unwrap();
+    assert_eq!("42", table.content_as_string(&conn));
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
@@ -4841,6 +6231,132 @@ fn row_wise_bulk_query_using_custom_row(profile: &Profile) {
     assert_eq!("Hallo, Welt!", batch[1].b.as_str().unwrap().unwrap());
 }
 
+#[cfg(feature = "derive")]
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn row_wise_bulk_query_binding_columns_out_of_order(profile: &Profile) {
+    // Given a cursor
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER", "VARCHAR(50)", "INTEGER"])
+        .values_by_column(&[&[Some("42")], &[Some("Hello, World!")], &[Some("5")]])
+        .build(profile)
+        .unwrap();
+    let query = format!("SELECT a, b, c FROM {table_name} ORDER BY id");
+    let cursor = conn.execute(&query, ()).unwrap().unwrap();
+
+    // When
+    #[derive(Clone, Copy, Default, Fetch)]
+    struct MyRow(
+        #[odbc(column = 3)] i32,
+        #[odbc(column = 1)] i32,
+        #[odbc(skip)] bool,
+    );
+    let row_set_buffer = RowVec::<MyRow>::new(10);
+    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
+    let batch = block_cursor.fetch().unwrap().unwrap();
+
+    // Then
+    assert_eq!(1, batch.num_rows());
+    assert_eq!(5, batch[0].0);
+    assert_eq!(42, batch[0].1);
+    assert!(!batch[0].2);
+    drop(block_cursor);
+
+    // When binding more columns than the result set has
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    #[derive(Clone, Copy, Default, Fetch)]
+    struct TooWide(#[odbc(column = 4)] i32);
+    let result = cursor.bind_buffer(RowVec::<TooWide>::new(10));
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::ColumnCountMismatch {
+            expected: 4,
+            actual: 3
+        })
+    ));
+}
+
+#[cfg(feature = "derive")]
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn fetch_rows_by_column_name(profile: &Profile) {
+    // Given a cursor
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["INTEGER", "VARCHAR(50)"])
+        .values_by_column(&[
+            &[Some("42"), None],
+            &[Some("Hello, World!"), Some("Hallo, Welt!")],
+        ])
+        .build(profile)
+        .unwrap();
+    let query = format!("SELECT b, a FROM {table_name} ORDER BY id");
+    let cursor = conn.execute(&query, ()).unwrap().unwrap();
+
+    // When
+    #[derive(FromRow)]
+    struct MyRow {
+        a: Option<i32>,
+        #[odbc(rename = "b")]
+        text: String,
+    }
+    let rows = cursor
+        .rows_as::<MyRow>()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    // Then
+    assert_eq!(2, rows.len());
+    assert_eq!(Some(42), rows[0].a);
+    assert_eq!("Hello, World!", rows[0].text);
+    assert_eq!(None, rows[1].a);
+    assert_eq!("Hallo, Welt!", rows[1].text);
+}
+
+#[cfg(feature = "derive")]
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn fetch_rows_by_column_name_reports_missing_column(profile: &Profile) {
+    // Given a cursor
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .values_by_column(&[&[Some("42")]])
+        .build(profile)
+        .unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+
+    // When
+    #[allow(dead_code)]
+    #[derive(FromRow)]
+    struct MyRow {
+        a: i32,
+        c: String,
+    }
+    let result = cursor.rows_as::<MyRow>().fetch();
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::ColumnNameMismatch { missing, .. }) if missing == ["c"]
+    ));
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]